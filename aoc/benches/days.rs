@@ -0,0 +1,52 @@
+//! Parse, part 1 and part 2 benchmarks for every day, run against each day's real input.
+//!
+//! Save a baseline with `cargo bench -p aoc --bench days -- --save-baseline main` and
+//! compare a later run against it with `cargo bench -p aoc --bench days -- --baseline main`.
+//! Inputs are resolved the same way as the runner, so `AOC_INPUT_XX` overrides
+//! `day_XX/input.txt`; days without an input are skipped.
+
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_day<D: Solution>(c: &mut Criterion) {
+    let default = PathBuf::from(format!(
+        "{}/../day_{:02}/input.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        D::DAY
+    ));
+    let path = aoc_core::input_path(D::DAY, None, &default);
+    let input = match aoc_core::read_input(&path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Skipping day {:02}: {}: {}", D::DAY, path.display(), err);
+            return;
+        }
+    };
+    let parsed = match D::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Skipping day {:02}: {}", D::DAY, err);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day_{:02}", D::DAY));
+    group.bench_function("parse", |b| b.iter(|| D::parse(black_box(&input))));
+    group.bench_function("part_01", |b| b.iter(|| D::part_01(black_box(&parsed))));
+    group.bench_function("part_02", |b| b.iter(|| D::part_02(black_box(&parsed))));
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day_01::Day01>,
+    bench_day::<day_02::Day02>,
+    bench_day::<day_03::Day03>,
+    bench_day::<day_04::Day04>,
+    bench_day::<day_05::Day05>,
+    bench_day::<day_06::Day06>,
+);
+criterion_main!(days);
@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use day_01::{calibrate, sum_calibration, Vocabulary};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Show how every line decodes: first and last digit, their character columns and the value
+    Report {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 2)]
+        part: u8,
+        /// `token = value` lines to use instead of the part's digits
+        #[arg(long, conflicts_with = "part")]
+        vocabulary: Option<PathBuf>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Stream the input line by line and print the calibration total, for inputs too large to
+    /// load into memory
+    Sum {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 2)]
+        part: u8,
+        /// `token = value` lines to use instead of the part's digits
+        #[arg(long, conflicts_with = "part")]
+        vocabulary: Option<PathBuf>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn load_vocabulary(part: u8, path: Option<PathBuf>) -> Result<Vocabulary, String> {
+    match path {
+        Some(path) => std::fs::read_to_string(&path)
+            .map_err(|err| format!("{}: {}", path.display(), err))?
+            .parse::<Vocabulary>()
+            .map_err(|err| err.to_string()),
+        None if part == 1 => Ok(Vocabulary::digits()),
+        None => Ok(Vocabulary::english()),
+    }
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Report {
+            part,
+            vocabulary,
+            input,
+        } => {
+            let vocabulary = load_vocabulary(part, vocabulary)?;
+
+            let input = crate::read_input(1, input.as_deref())?;
+            let lines = input.lines().collect::<Vec<_>>();
+
+            let mut total = 0;
+            let mut missing = 0;
+            for calibration in calibrate(&vocabulary, &lines) {
+                println!("{}", calibration);
+                match calibration.value() {
+                    Some(value) => total += value,
+                    None => missing += 1,
+                }
+            }
+
+            println!("Total: {}", total);
+            println!("Lines without a value: {}", missing);
+            Ok(())
+        }
+        Command::Sum {
+            part,
+            vocabulary,
+            input,
+        } => {
+            let vocabulary = load_vocabulary(part, vocabulary)?;
+
+            let default = crate::verify::input_path(1);
+            let path = aoc_core::input_path(1, input.as_deref(), &default);
+            let total = aoc_core::open_input(&path)
+                .and_then(|reader| sum_calibration(reader, &vocabulary))
+                .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+            println!("{}", total);
+            Ok(())
+        }
+    }
+}
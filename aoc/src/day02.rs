@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use aoc_core::Solution;
+use clap::{Subcommand, ValueEnum};
+use day_02::{default_bag, possible_id_sum, Day02, GameLog, Round};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// `Game <id>: <rounds>` lines, as in the puzzle input
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check every game against a bag and report which limits it breaks, and by how much
+    Check {
+        /// Bag contents such as `12 red, 13 green, 14 blue`. Defaults to the puzzle's bag
+        #[arg(long)]
+        bag: Option<String>,
+        /// File with the bag contents, one `<count> <colour>` per line or comma separated
+        #[arg(long, conflicts_with = "bag")]
+        bag_file: Option<PathBuf>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Show the minimal bag for each game, and the smallest bag covering a chosen set of games
+    Bags {
+        /// Comma separated game ids to find a common bag for
+        #[arg(long, value_delimiter = ',')]
+        games: Vec<u32>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Per-colour draw statistics, the most likely bag and the games that stand out
+    Stats {
+        /// How many standard deviations above the mean a game's largest draw must be
+        #[arg(long, default_value_t = 2.0)]
+        threshold: f64,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Re-emit the game log in canonical form, optionally keeping only some games
+    Convert {
+        #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+        from: LogFormat,
+        #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+        to: LogFormat,
+        /// Comma separated game ids to keep
+        #[arg(long, value_delimiter = ',')]
+        games: Vec<u32>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+/// The bag text from `--bag` or `--bag-file`, if either was given.
+fn read_bag(bag: Option<String>, bag_file: Option<PathBuf>) -> Result<Option<String>, String> {
+    match (bag, bag_file) {
+        (Some(bag), _) => Ok(Some(bag)),
+        (None, Some(path)) => std::fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|err| format!("{}: {}", path.display(), err)),
+        (None, None) => Ok(None),
+    }
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Check {
+            bag,
+            bag_file,
+            input,
+        } => {
+            let bag = read_bag(bag, bag_file)?;
+            let bag = match bag.as_deref() {
+                Some(bag) => Round::try_from(bag).map_err(|err| err.to_string())?,
+                None => default_bag(),
+            };
+            let input = crate::read_input(Day02::DAY, input.as_deref())?;
+            let log = Day02::parse(&input).map_err(|err| err.to_string())?;
+
+            println!("Bag: {}", bag);
+            for game in log.games() {
+                let violations = game.violations(&bag);
+                if violations.is_empty() {
+                    println!("Game {}: possible", game.id);
+                } else {
+                    let violations = violations.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                    println!("Game {}: impossible: {}", game.id, violations.join(", "));
+                }
+            }
+            println!("Sum of possible game ids: {}", possible_id_sum(&log, &bag));
+            Ok(())
+        }
+        Command::Bags { games, input } => {
+            let input = crate::read_input(Day02::DAY, input.as_deref())?;
+            let log = Day02::parse(&input).map_err(|err| err.to_string())?;
+
+            for game in log.games() {
+                println!("Game {}: {}", game.id, game.minimal_bag());
+            }
+
+            if !games.is_empty() {
+                let bag = log
+                    .smallest_bag(&games)
+                    .map_err(|id| format!("Game {} is not in the log", id))?;
+                println!("Smallest bag for {:?}: {}", games, bag);
+            }
+            Ok(())
+        }
+        Command::Stats { threshold, input } => {
+            let input = crate::read_input(Day02::DAY, input.as_deref())?;
+            let log = Day02::parse(&input).map_err(|err| err.to_string())?;
+
+            for distribution in log.distributions() {
+                println!("{}", distribution);
+            }
+            let bag = log.maximum_likelihood_bag();
+            println!(
+                "Most likely bag: {} (log likelihood {:.2})",
+                bag,
+                log.log_likelihood(&bag)
+            );
+            let outliers = log.outliers(threshold);
+            println!(
+                "Outliers above {} standard deviations: {}",
+                threshold,
+                outliers.len()
+            );
+            for outlier in outliers {
+                println!("{}", outlier);
+            }
+            Ok(())
+        }
+        Command::Convert {
+            from,
+            to,
+            games,
+            input,
+        } => {
+            let input = crate::read_input(Day02::DAY, input.as_deref())?;
+            let mut log = match from {
+                LogFormat::Text => Day02::parse(&input).map_err(|err| err.to_string())?,
+                LogFormat::Json => serde_json::from_str::<GameLog>(&input)
+                    .map_err(|err| format!("Day 02: {}", err))?,
+            };
+            if !games.is_empty() {
+                log = log.filter(|game| games.contains(&game.id));
+            }
+
+            match to {
+                LogFormat::Text => println!("{}", log),
+                LogFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&log).map_err(|err| err.to_string())?
+                ),
+            }
+            Ok(())
+        }
+    }
+}
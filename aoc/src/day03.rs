@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use aoc_core::Solution;
+use clap::{Args, Subcommand, ValueEnum};
+use day_03::{Aggregate, Count, Day03, GearRule, Schematic, Tokenizer};
+
+/// Which symbols count as gears; the defaults are the puzzle's rule.
+#[derive(Args)]
+pub struct Rule {
+    #[arg(long, default_value_t = '*')]
+    symbol: char,
+    /// Parts the symbol must touch: `N`, `>=N` or `<=N`
+    #[arg(long, default_value = "2", allow_hyphen_values = true)]
+    count: Count,
+    /// How the touching parts combine: `product`, `sum` or `max`
+    #[arg(long, default_value = "product")]
+    aggregate: Aggregate,
+}
+
+impl Rule {
+    fn gear_rule(&self) -> GearRule {
+        GearRule::new(self.symbol)
+            .count(self.count)
+            .aggregate(self.aggregate)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RenderFormat {
+    Ansi,
+    Html,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Score every symbol by the parts around it
+    Gears {
+        #[command(flatten)]
+        rule: Rule,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Draw the schematic with counted parts, uncounted numbers and gears coloured
+    Render {
+        #[command(flatten)]
+        rule: Rule,
+        #[arg(long, value_enum, default_value_t = RenderFormat::Ansi)]
+        format: RenderFormat,
+        /// File to write instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn load_schematic(input: Option<PathBuf>) -> Result<Schematic, String> {
+    let input = crate::read_input(Day03::DAY, input.as_deref())?;
+    // Not `Day03::parse`, which rejects overflowing ratios under the puzzle's rule only.
+    Schematic::parse(&input, &Tokenizer::default()).map_err(|err| err.to_string())
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Gears { rule, input } => {
+            let schematic = load_schematic(input)?;
+
+            let gears = schematic.gears(&rule.gear_rule());
+            for gear in &gears {
+                let parts = gear
+                    .parts
+                    .iter()
+                    .map(|part| part.value.to_string())
+                    .collect::<Vec<_>>();
+                let score = gear
+                    .score
+                    .map_or_else(|| "out of range".to_string(), |score| score.to_string());
+                println!(
+                    "{} at ({}, {}): {} -> {}",
+                    rule.symbol,
+                    gear.pos.0,
+                    gear.pos.1,
+                    parts.join(", "),
+                    score
+                );
+            }
+            let total = schematic
+                .gear_sum(&rule.gear_rule())
+                .map_err(|(x, y)| format!("total out of range at the symbol at ({}, {})", x, y))?;
+            println!(
+                "{} symbols with {} parts, total: {}",
+                gears.len(),
+                rule.count,
+                total
+            );
+            Ok(())
+        }
+        Command::Render {
+            rule,
+            format,
+            output,
+            input,
+        } => {
+            let schematic = load_schematic(input)?;
+            let rendered = match format {
+                RenderFormat::Ansi => schematic.render_ansi(&rule.gear_rule()) + "\n",
+                RenderFormat::Html => schematic.render_html(&rule.gear_rule()),
+            };
+
+            match output {
+                Some(path) => std::fs::write(&path, rendered)
+                    .map_err(|err| format!("{}: {}", path.display(), err)),
+                None => {
+                    print!("{}", rendered);
+                    Ok(())
+                }
+            }
+        }
+    }
+}
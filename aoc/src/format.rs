@@ -0,0 +1,71 @@
+use aoc_core::Report;
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// One solved part, flattened for dashboards. Times are in nanoseconds.
+#[derive(Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,parse_ns,solve_ns";
+
+impl Record {
+    pub fn from_report(report: &Report) -> impl Iterator<Item = Record> + '_ {
+        report.parts.iter().map(|part| Record {
+            day: report.day,
+            part: part.part,
+            answer: part.answer.clone(),
+            parse_ns: report.parse.as_nanos(),
+            solve_ns: part.time.as_nanos(),
+        })
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.day,
+            self.part,
+            csv_field(&self.answer),
+            self.parse_ns,
+            self.solve_ns
+        )
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_csv() {
+        let record = Record {
+            day: 5,
+            part: 2,
+            answer: "a,\"b\"".to_string(),
+            parse_ns: 10,
+            solve_ns: 20,
+        };
+
+        assert_eq!(record.to_csv(), "5,2,\"a,\"\"b\"\"\",10,20");
+    }
+}
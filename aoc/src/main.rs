@@ -0,0 +1,227 @@
+mod day01;
+mod day02;
+mod day03;
+mod format;
+mod verify;
+
+use std::path::{Path, PathBuf};
+
+use aoc_core::{ParseError, Report, Solution};
+use clap::{Parser, Subcommand};
+use format::{Format, Record};
+use verify::Status;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day if no day is given
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin. Defaults to `$AOC_INPUT_XX`, then `day_XX/input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check every day's answers against the `day_XX/answers.txt` recorded answers
+    Verify {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Record the current answers as the expected ones
+        #[arg(long)]
+        update: bool,
+    },
+    /// List the available days
+    List,
+    /// Day 01 calibration tools
+    Day01 {
+        #[command(subcommand)]
+        command: day01::Command,
+    },
+    /// Day 02 cube game tools
+    Day02 {
+        #[command(subcommand)]
+        command: day02::Command,
+    },
+    /// Day 03 engine schematic tools
+    Day03 {
+        #[command(subcommand)]
+        command: day03::Command,
+    },
+}
+
+struct Day {
+    day: u8,
+    run: fn(&str, Option<u8>) -> Result<Report, ParseError>,
+}
+
+/// Reads a day's input from `input`, `$AOC_INPUT_XX` or `day_XX/input.txt`, in that order.
+fn read_input(day: u8, input: Option<&Path>) -> Result<String, String> {
+    let path = aoc_core::input_path(day, input, &verify::input_path(day));
+    aoc_core::read_input(&path).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+const fn day<D: Solution>() -> Day {
+    Day {
+        day: D::DAY,
+        run: aoc_core::run::<D>,
+    }
+}
+
+const DAYS: &[Day] = &[
+    day::<day_01::Day01>(),
+    day::<day_02::Day02>(),
+    day::<day_03::Day03>(),
+    day::<day_04::Day04>(),
+    day::<day_05::Day05>(),
+    day::<day_06::Day06>(),
+];
+
+fn select(day: Option<u8>) -> Vec<&'static Day> {
+    match day {
+        Some(n) => match DAYS.iter().find(|d| d.day == n) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {:02} is not implemented", n);
+                std::process::exit(1);
+            }
+        },
+        None => DAYS.iter().collect(),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::List => {
+            for day in DAYS {
+                println!("Day {:02}", day.day);
+            }
+        }
+        Command::Day01 { command } => {
+            if let Err(err) = day01::run(command) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Command::Day02 { command } => {
+            if let Err(err) = day02::run(command) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Command::Day03 { command } => {
+            if let Err(err) = day03::run(command) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let mut records = Vec::new();
+            if format == Format::Csv {
+                println!("{}", format::CSV_HEADER);
+            }
+
+            for day in select(day) {
+                let input = match read_input(day.day, input.as_deref()) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        eprintln!("Day {:02}: {}", day.day, err);
+                        std::process::exit(1);
+                    }
+                };
+
+                let report = match (day.run)(&input, part) {
+                    Ok(report) => report,
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    }
+                };
+
+                match format {
+                    Format::Text => print!("Day {:02}\n{}", day.day, report),
+                    Format::Json => records.extend(Record::from_report(&report)),
+                    Format::Csv => {
+                        for record in Record::from_report(&report) {
+                            println!("{}", record.to_csv());
+                        }
+                    }
+                }
+            }
+
+            if format == Format::Json {
+                match serde_json::to_string_pretty(&records) {
+                    Ok(json) => println!("{}", json),
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        Command::Verify { day, update } => {
+            let mut ok = true;
+
+            for day in select(day) {
+                let input = verify::input_path(day.day);
+                let report = aoc_core::read_input(&input)
+                    .map_err(|err| format!("{}: {}", input.display(), err))
+                    .and_then(|input| (day.run)(&input, None).map_err(|err| err.to_string()));
+                let report = match report {
+                    Ok(report) => report,
+                    Err(err) => {
+                        println!("Day {:02}: FAIL {}", day.day, err);
+                        ok = false;
+                        continue;
+                    }
+                };
+
+                let path = verify::answers_path(day.day);
+                let mut answers = match verify::load(&path) {
+                    Ok(answers) => answers,
+                    Err(err) => {
+                        println!("Day {:02}: FAIL {}", day.day, err);
+                        ok = false;
+                        continue;
+                    }
+                };
+
+                for part in &report.parts {
+                    let status = Status::check(&answers, part.part, &part.answer);
+                    println!("Day {:02} Part {:02}: {}", day.day, part.part, status);
+                    ok &= update || !matches!(status, Status::Changed { .. });
+                }
+
+                if update {
+                    for part in report.parts {
+                        answers.insert(part.part, part.answer);
+                    }
+                    if let Err(err) = verify::save(&path, &answers) {
+                        eprintln!("{}: {}", path.display(), err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            if !ok {
+                std::process::exit(1);
+            }
+        }
+    }
+}
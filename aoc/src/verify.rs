@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Expected answers for a day, keyed by part.
+pub type Answers = BTreeMap<u8, String>;
+
+/// A day's crate directory, found from this crate's rather than the working directory.
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("{}/../day_{:02}", env!("CARGO_MANIFEST_DIR"), day))
+}
+
+pub fn answers_path(day: u8) -> PathBuf {
+    day_dir(day).join("answers.txt")
+}
+
+/// The input the recorded answers belong to. Unlike `aoc run`, this ignores `$AOC_INPUT_XX`,
+/// which may point at another account's input.
+pub fn input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+/// Reads `part_XX = <answer>` lines, skipping blanks and `#` comments. A missing file has no
+/// recorded answers.
+pub fn load(path: &Path) -> io::Result<Answers> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::new()),
+        Err(err) => return Err(err),
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.split_once('=')
+                .and_then(|(key, value)| {
+                    let part = key.trim().strip_prefix("part_")?.parse().ok()?;
+                    Some((part, value.trim().to_string()))
+                })
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "{}: expected `part_XX = <answer>`, found `{}`",
+                            path.display(),
+                            line
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+pub fn save(path: &Path, answers: &Answers) -> io::Result<()> {
+    let contents = answers
+        .iter()
+        .map(|(part, answer)| format!("part_{:02} = {}\n", part, answer))
+        .collect::<String>();
+    std::fs::write(path, contents)
+}
+
+pub enum Status<'a> {
+    Pass(&'a str),
+    Changed { expected: &'a str, actual: &'a str },
+    New(&'a str),
+}
+
+impl<'a> Status<'a> {
+    pub fn check(answers: &'a Answers, part: u8, actual: &'a str) -> Self {
+        match answers.get(&part) {
+            Some(expected) if expected == actual => Status::Pass(actual),
+            Some(expected) => Status::Changed { expected, actual },
+            None => Status::New(actual),
+        }
+    }
+}
+
+impl Display for Status<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass(actual) => write!(f, "PASS {}", actual),
+            Status::Changed { expected, actual } => {
+                write!(f, "CHANGED expected {}, got {}", expected, actual)
+            }
+            Status::New(actual) => write!(f, "NEW {}", actual),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::from([(1, "55017".to_string())]);
+
+        assert!(matches!(
+            Status::check(&answers, 1, "55017"),
+            Status::Pass(_)
+        ));
+        assert!(matches!(
+            Status::check(&answers, 1, "55018"),
+            Status::Changed {
+                expected: "55017",
+                ..
+            }
+        ));
+        assert!(matches!(Status::check(&answers, 2, "1"), Status::New(_)));
+    }
+}
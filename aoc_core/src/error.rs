@@ -0,0 +1,69 @@
+use std::fmt::{self, Display};
+
+/// A malformed puzzle input, located by 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Points the error at `token`, which must be a slice of `source` (the full text of line
+    /// `line`); the column is recovered from the slice offset.
+    pub fn at(day: u8, line: usize, source: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(source.as_ptr() as usize);
+        let column = source[..offset.min(source.len())].chars().count() + 1;
+        Self::new(day, line, column, token, message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02}: line {}, column {}: {}: `{}`",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let line = "Game 1: 3 blue, 4 purple";
+        let token = &line[18..];
+        let error = ParseError::at(2, 7, line, token, "unknown colour");
+
+        assert_eq!(error.line, 7);
+        assert_eq!(error.column, 19);
+        assert_eq!(error.text, "purple");
+        assert_eq!(
+            error.to_string(),
+            "Day 02: line 7, column 19: unknown colour: `purple`"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A cell position as `(x, y)`: column, then row, both from zero.
+pub type Point = (usize, usize);
+
+/// A dense, row-major rectangle of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows; `None` if they are not all the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Option<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            if *width.get_or_insert(row.len()) != row.len() {
+                return None;
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        Some(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one row per line and one cell per `char`, rejecting ragged lines and any
+    /// character `cell` does not accept. Errors are reported against `day`.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::new(day, index + 1, column + 1, c, "unexpected character")
+                })?;
+                cells.push(value);
+            }
+
+            let len = cells.len() - start;
+            let width = *width.get_or_insert(len);
+            if len != width {
+                return Err(ParseError::new(
+                    day,
+                    index + 1,
+                    len.min(width) + 1,
+                    line,
+                    format!("expected {} columns, found {}", width, len),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Exactly `height` rows, so a grid of zero width still has its empty rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| ((index % self.width, index / self.width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The up to four positions sharing an edge with `point`.
+    pub fn neighbours4(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        [
+            (x.checked_sub(1), Some(y)),
+            (Some(x + 1), Some(y)),
+            (Some(x), y.checked_sub(1)),
+            (Some(x), Some(y + 1)),
+        ]
+        .into_iter()
+        .filter_map(|point| match point {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None,
+        })
+        .filter(|&point| self.contains(point))
+    }
+
+    /// The up to eight positions sharing an edge or a corner with `point`.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.surrounding(point, 1)
+    }
+
+    /// The positions touching the horizontal run of `width` cells starting at `point`,
+    /// including diagonally, row by row.
+    pub fn surrounding(&self, (x, y): Point, width: usize) -> impl Iterator<Item = Point> + '_ {
+        let xs = x.saturating_sub(1)..(x + width + 1).min(self.width);
+        let ys = y.saturating_sub(1)..(y + 2).min(self.height);
+        ys.flat_map(move |ny| xs.clone().map(move |nx| (nx, ny)))
+            .filter(move |&(nx, ny)| ny != y || nx + 1 == x || nx == x + width)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} out of bounds for a {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} out of bounds for a {}x{} grid", point, width, height),
+        }
+    }
+}
+
+/// Writes one line per row, each cell by its own `Display`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(3, "ab1\n2cd", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], '1');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &['2', 'c', 'd']);
+        assert_eq!(grid.column(0).collect::<String>(), "a2");
+        assert_eq!(grid.to_string(), "ab1\n2cd");
+
+        let err = Grid::parse(3, "abc\nab", Some).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Grid::parse(3, "abc\na#c", |c| c.is_alphabetic().then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "#"));
+
+        assert_eq!(Grid::<char>::parse(3, "", Some).unwrap().rows().count(), 0);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let grid = Grid::<char>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 2));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[] as &[char]; 2]);
+        assert_eq!(grid.to_string(), "\n");
+        assert_eq!(Grid::new(0, 2, 'x').rows().count(), 2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((3, 2)).collect::<Vec<_>>(),
+            vec![(2, 1), (3, 1), (2, 2)]
+        );
+        assert_eq!(
+            grid.surrounding((1, 0), 2).collect::<Vec<_>>(),
+            vec![(0, 0), (3, 0), (0, 1), (1, 1), (2, 1), (3, 1)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(4, 0) out of bounds for a 4x3 grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new(4, 3, 0);
+        let _ = grid[(4, 0)];
+    }
+}
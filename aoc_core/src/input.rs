@@ -0,0 +1,55 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Picks where a day's input comes from: an explicit `path` wins, then the `AOC_INPUT_XX`
+/// environment variable for that day, then `default`.
+pub fn input_path(day: u8, path: Option<&Path>, default: &Path) -> PathBuf {
+    path.map(Path::to_path_buf)
+        .or_else(|| std::env::var_os(input_var(day)).map(PathBuf::from))
+        .unwrap_or_else(|| default.to_path_buf())
+}
+
+pub fn input_var(day: u8) -> String {
+    format!("AOC_INPUT_{:02}", day)
+}
+
+/// Reads the input at `path`, with `-` meaning stdin.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Opens the input at `path` for streaming, with `-` meaning stdin.
+pub fn open_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let default = Path::new("day_25/input.txt");
+
+        assert_eq!(input_var(5), "AOC_INPUT_05");
+        assert_eq!(
+            input_path(25, Some(Path::new("other.txt")), default),
+            Path::new("other.txt")
+        );
+        assert_eq!(
+            input_path(25, Some(Path::new("-")), default),
+            Path::new("-")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+mod error;
+mod grid;
+mod input;
+
+pub use error::ParseError;
+pub use grid::{Grid, Point};
+pub use input::{input_path, input_var, open_input, read_input};
+
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// A single day's puzzle: parse the raw input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_01(input: &Self::Input<'_>) -> Self::Answer;
+    fn part_02(input: &Self::Input<'_>) -> Self::Answer;
+}
+
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// Parses `input` and solves the requested part (or both), timing each phase separately.
+pub fn run<D: Solution>(input: &str, part: Option<u8>) -> Result<Report, ParseError> {
+    let now = Instant::now();
+    let parsed = D::parse(input)?;
+    let parse = now.elapsed();
+
+    let mut parts = Vec::new();
+
+    if part.unwrap_or(1) == 1 {
+        let now = Instant::now();
+        let answer = D::part_01(&parsed);
+        let time = now.elapsed();
+        parts.push(PartReport {
+            part: 1,
+            answer: answer.to_string(),
+            time,
+        });
+    }
+
+    if part.unwrap_or(2) == 2 {
+        let now = Instant::now();
+        let answer = D::part_02(&parsed);
+        let time = now.elapsed();
+        parts.push(PartReport {
+            part: 2,
+            answer: answer.to_string(),
+            time,
+        });
+    }
+
+    Ok(Report {
+        day: D::DAY,
+        parse,
+        parts,
+    })
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Parse Time: {}µs", self.parse.as_micros())?;
+        for part in &self.parts {
+            writeln!(f, "Part {:02}: {}", part.part, part.answer)?;
+            writeln!(f, "Time: {}µs", part.time.as_micros())?;
+        }
+        Ok(())
+    }
+}
+
+/// Entry point shared by the per-day binaries: `day_XX [PATH | -]`.
+pub fn main<D: Solution>() {
+    let path = std::env::args().nth(1).map(PathBuf::from);
+    let path = input_path(D::DAY, path.as_deref(), Path::new("input.txt"));
+
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+    };
+
+    match run::<D>(&input, None) {
+        Ok(report) => print!("{}", report),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
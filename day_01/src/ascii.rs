@@ -0,0 +1,159 @@
+use crate::vocabulary::{concat, Vocabulary};
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH: u64 = 0x8080_8080_8080_8080;
+
+/// Byte-level counterpart of [`Vocabulary`] for ASCII text. Tokens are bucketed by their first
+/// byte, longest first, and a vocabulary of plain digits skips token matching entirely in
+/// favour of scanning eight bytes at a time.
+pub struct AsciiVocabulary {
+    digits_only: bool,
+    tokens: Vec<Vec<(Vec<u8>, usize)>>,
+}
+
+impl AsciiVocabulary {
+    /// `None` if any token is not ASCII, in which case only [`Vocabulary`] can decode it.
+    pub fn new(vocabulary: &Vocabulary) -> Option<Self> {
+        let mut tokens = vec![Vec::new(); 128];
+        for token in vocabulary.tokens() {
+            let bytes = token.text.as_bytes();
+            match bytes.first() {
+                Some(&first) if token.text.is_ascii() => {
+                    tokens[first as usize].push((bytes.to_vec(), token.value))
+                }
+                Some(_) => return None,
+                None => {}
+            }
+        }
+        for bucket in tokens.iter_mut() {
+            bucket.sort_by_key(|(bytes, _)| std::cmp::Reverse(bytes.len()));
+        }
+
+        Some(Self {
+            digits_only: *vocabulary == Vocabulary::digits(),
+            tokens,
+        })
+    }
+
+    fn match_at(&self, line: &[u8], offset: usize) -> Option<usize> {
+        let rest = &line[offset..];
+        self.tokens
+            .get(rest[0] as usize)?
+            .iter()
+            .find(|(bytes, _)| rest.starts_with(bytes))
+            .map(|&(_, value)| value)
+    }
+
+    /// Same result as [`Vocabulary::calibration_value`] for an ASCII line.
+    pub fn calibration_value(&self, line: &[u8]) -> Option<usize> {
+        if self.digits_only {
+            let first = line[first_digit(line)?] - b'0';
+            let last = line[last_digit(line)?] - b'0';
+            return concat(first as usize, last as usize);
+        }
+
+        let first = (0..line.len()).find_map(|offset| self.match_at(line, offset))?;
+        let last = (0..line.len())
+            .rev()
+            .find_map(|offset| self.match_at(line, offset))?;
+        concat(first, last)
+    }
+}
+
+/// One bit set at the top of every byte of `chunk` that is an ASCII digit. Each byte is tested
+/// without carries or borrows crossing into its neighbours, so every bit is exact.
+fn digit_mask(chunk: u64) -> u64 {
+    // Digits are 0x30..=0x39: high nibble 3 and low nibble below 10.
+    let high = (chunk & 0xF0F0_F0F0_F0F0_F0F0) ^ 0x3030_3030_3030_3030;
+    let low = chunk & 0x0F0F_0F0F_0F0F_0F0F;
+
+    let high_nonzero = (((high & !HIGH) + !HIGH) | high) & HIGH;
+    let low_too_big = ((low + 6 * ONES) & (0x10 * ONES)) << 3;
+
+    !(high_nonzero | low_too_big) & HIGH
+}
+
+fn first_digit(line: &[u8]) -> Option<usize> {
+    let mut chunks = line.chunks_exact(8);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if mask != 0 {
+            return Some(index * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+
+    let tail = line.len() - chunks.remainder().len();
+    chunks
+        .remainder()
+        .iter()
+        .position(u8::is_ascii_digit)
+        .map(|index| tail + index)
+}
+
+fn last_digit(line: &[u8]) -> Option<usize> {
+    let mut chunks = line.rchunks_exact(8);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        if mask != 0 {
+            let start = line.len() - (index + 1) * 8;
+            return Some(start + 7 - mask.leading_zeros() as usize / 8);
+        }
+    }
+
+    chunks.remainder().iter().rposition(u8::is_ascii_digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random lines built from digit and word fragments.
+    fn lines() -> Vec<String> {
+        let fragments = [
+            "one", "two", "thr", "three", "four", "fiv", "five", "six", "seven", "eight", "nine",
+            "ei", "ght", "x", "z", "q", "0", "1", "5", "9", "/", ":", "`",
+        ];
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        (0..2000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let len = (state % 24) as usize;
+                (0..len)
+                    .map(|i| fragments[((state >> (i * 2)) as usize + i * 7) % fragments.len()])
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_digit_mask() {
+        let chunk = u64::from_le_bytes(*b"/0a9:5Z`");
+        assert_eq!(digit_mask(chunk), 0x0000_8000_8000_8000);
+    }
+
+    #[test]
+    fn test_matches_reference() {
+        for vocabulary in [
+            Vocabulary::digits(),
+            Vocabulary::english(),
+            Vocabulary::english().with("ei", 7).with("zero", 0),
+        ] {
+            let ascii = AsciiVocabulary::new(&vocabulary).unwrap();
+            for line in lines() {
+                assert_eq!(
+                    ascii.calibration_value(line.as_bytes()),
+                    vocabulary.calibration_value(&line),
+                    "{}",
+                    line
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_vocabulary() {
+        assert!(AsciiVocabulary::new(&Vocabulary::new().with("fünf", 5)).is_none());
+    }
+}
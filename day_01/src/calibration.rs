@@ -0,0 +1,95 @@
+use std::fmt::{self, Display};
+
+use crate::vocabulary::{concat, Match, Vocabulary};
+
+/// How a single line decoded: its first and last matches, if it had any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    pub line: usize,
+    pub text: &'a str,
+    pub first: Option<Match<'a>>,
+    pub last: Option<Match<'a>>,
+}
+
+impl Calibration<'_> {
+    /// The 1-based character column of `m` in the line, as opposed to its byte offset.
+    pub fn column(&self, m: &Match) -> usize {
+        self.text[..m.offset].chars().count() + 1
+    }
+
+    pub fn value(&self) -> Option<usize> {
+        concat(self.first?.value, self.last?.value)
+    }
+
+    pub fn has_digits(&self) -> bool {
+        self.first.is_some()
+    }
+}
+
+/// Decodes every line, numbering them from 1. Lines without any match are kept and flagged
+/// rather than dropped.
+pub fn calibrate<'a, 'v>(
+    vocabulary: &'v Vocabulary,
+    lines: &'a [&'a str],
+) -> impl Iterator<Item = Calibration<'a>> + 'a
+where
+    'v: 'a,
+{
+    lines.iter().enumerate().map(|(index, line)| Calibration {
+        line: index + 1,
+        text: line,
+        first: vocabulary.first(line),
+        last: vocabulary.last(line),
+    })
+}
+
+impl Display for Calibration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.first, self.last, self.value()) {
+            (Some(first), Some(last), Some(value)) => write!(
+                f,
+                "{:>5}: first `{}` at {}, last `{}` at {} -> {}",
+                self.line,
+                first.token,
+                self.column(&first),
+                last.token,
+                self.column(&last),
+                value
+            ),
+            (Some(_), Some(_), None) => write!(f, "{:>5}: value out of range", self.line),
+            _ => write!(f, "{:>5}: no digits", self.line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibrate() {
+        let vocabulary = Vocabulary::english();
+        let lines = ["xtwone3four", "abc", "7"];
+        let calibrations = calibrate(&vocabulary, &lines).collect::<Vec<_>>();
+
+        assert_eq!(
+            calibrations.iter().map(|c| c.value()).collect::<Vec<_>>(),
+            vec![Some(24), None, Some(77)]
+        );
+        assert!(!calibrations[1].has_digits());
+        assert_eq!(
+            calibrations[0].to_string(),
+            "    1: first `two` at 2, last `four` at 8 -> 24"
+        );
+        assert_eq!(calibrations[1].to_string(), "    2: no digits");
+
+        let vocabulary = Vocabulary::new().with("drei", 3);
+        let lines = ["über drei"];
+        let calibration = calibrate(&vocabulary, &lines).next().unwrap();
+        assert_eq!(calibration.first.unwrap().offset, 6);
+        assert_eq!(
+            calibration.to_string(),
+            "    1: first `drei` at 6, last `drei` at 6 -> 33"
+        );
+    }
+}
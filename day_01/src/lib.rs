@@ -0,0 +1,93 @@
+mod ascii;
+mod calibration;
+mod stream;
+mod vocabulary;
+
+use aoc_core::{ParseError, Solution};
+
+pub use ascii::AsciiVocabulary;
+pub use calibration::{calibrate, Calibration};
+pub use stream::sum_calibration;
+pub use vocabulary::{Match, Token, Vocabulary};
+
+pub struct Document<'a> {
+    lines: Vec<&'a str>,
+    ascii: bool,
+}
+
+impl Document<'_> {
+    /// Uses the byte-level decoder when both the text and the vocabulary are ASCII, falling
+    /// back to the `char`-based reference decoder otherwise.
+    pub fn calibration_sum(&self, vocabulary: &Vocabulary) -> usize {
+        match AsciiVocabulary::new(vocabulary).filter(|_| self.ascii) {
+            Some(ascii) => self
+                .lines
+                .iter()
+                .filter_map(|line| ascii.calibration_value(line.as_bytes()))
+                .sum(),
+            None => calibrate(vocabulary, &self.lines)
+                .filter_map(|calibration| calibration.value())
+                .sum(),
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Document<'a>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Document<'_>, ParseError> {
+        Ok(Document {
+            lines: input.lines().collect(),
+            ascii: input.is_ascii(),
+        })
+    }
+
+    fn part_01(document: &Document) -> usize {
+        document.calibration_sum(&Vocabulary::digits())
+    }
+
+    fn part_02(document: &Document) -> usize {
+        document.calibration_sum(&Vocabulary::english())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(Day01::part_02(&Day01::parse(input).unwrap()), 281);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let input = "oneight
+eightwo
+sevenine
+twone";
+        assert_eq!(
+            Day01::part_02(&Day01::parse(input).unwrap()),
+            18 + 82 + 79 + 21
+        );
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        let input = "zwei1neun
+€eightwo€";
+        assert_eq!(Day01::part_02(&Day01::parse(input).unwrap()), 11 + 82);
+    }
+}
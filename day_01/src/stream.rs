@@ -0,0 +1,64 @@
+use std::io::{self, BufRead};
+
+use crate::ascii::AsciiVocabulary;
+use crate::vocabulary::Vocabulary;
+
+/// Sums the calibration values of every line read from `reader`. One line buffer is reused
+/// throughout, so memory is bounded by the longest line rather than the whole document and
+/// no allocation happens per line once the buffer has grown. Lines without digits add nothing.
+pub fn sum_calibration<R: BufRead>(mut reader: R, vocabulary: &Vocabulary) -> io::Result<usize> {
+    let ascii = AsciiVocabulary::new(vocabulary);
+    let mut line = String::new();
+    let mut total = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let line = line.trim_end_matches(['\n', '\r']);
+        let value = match &ascii {
+            Some(ascii) if line.is_ascii() => ascii.calibration_value(line.as_bytes()),
+            _ => vocabulary.calibration_value(line),
+        };
+        total += value.unwrap_or(0);
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_sum_calibration() {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+        assert_eq!(
+            sum_calibration(input.as_bytes(), &Vocabulary::english()).unwrap(),
+            281
+        );
+        assert_eq!(
+            sum_calibration(
+                input.replace('\n', "\r\n").as_bytes(),
+                &Vocabulary::english()
+            )
+            .unwrap(),
+            Day01::part_02(&Day01::parse(input).unwrap())
+        );
+        assert_eq!(
+            sum_calibration(input.as_bytes(), &Vocabulary::digits()).unwrap(),
+            Day01::part_01(&Day01::parse(input).unwrap())
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::str::FromStr;
+
+use aoc_core::{ParseError, Solution};
+
+use crate::Day01;
+
+const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub value: usize,
+}
+
+/// A token found in a line, at byte `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'v> {
+    pub token: &'v str,
+    pub value: usize,
+    pub offset: usize,
+}
+
+/// The set of tokens the calibration decoder recognises. Tokens may overlap in the text
+/// (`oneight`); where several start at the same offset the longest wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<Token>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The numeric digits `0`..`9`.
+    pub fn digits() -> Self {
+        DIGITS
+            .iter()
+            .enumerate()
+            .fold(Self::new(), |vocabulary, (value, text)| {
+                vocabulary.with(*text, value)
+            })
+    }
+
+    /// The numeric digits plus `one`..`nine`.
+    pub fn english() -> Self {
+        ENGLISH
+            .iter()
+            .enumerate()
+            .fold(Self::digits(), |vocabulary, (value, text)| {
+                vocabulary.with(*text, value + 1)
+            })
+    }
+
+    pub fn with(mut self, text: impl Into<String>, value: usize) -> Self {
+        self.insert(text, value);
+        self
+    }
+
+    /// Adds a token, replacing the value of an existing token with the same text.
+    pub fn insert(&mut self, text: impl Into<String>, value: usize) {
+        let text = text.into();
+        match self.tokens.iter_mut().find(|token| token.text == text) {
+            Some(token) => token.value = value,
+            None => self.tokens.push(Token { text, value }),
+        }
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn match_at(&self, line: &str, offset: usize) -> Option<Match<'_>> {
+        let rest = line.get(offset..)?;
+        self.tokens
+            .iter()
+            .filter(|token| !token.text.is_empty() && rest.starts_with(&token.text))
+            .max_by_key(|token| token.text.len())
+            .map(|token| Match {
+                token: &token.text,
+                value: token.value,
+                offset,
+            })
+    }
+
+    /// Every match in `line`, left to right, including overlapping ones.
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match<'a>> + 'a {
+        line.char_indices()
+            .filter_map(move |(offset, _)| self.match_at(line, offset))
+    }
+
+    pub fn first(&self, line: &str) -> Option<Match<'_>> {
+        line.char_indices()
+            .find_map(|(offset, _)| self.match_at(line, offset))
+    }
+
+    pub fn last(&self, line: &str) -> Option<Match<'_>> {
+        line.char_indices()
+            .rev()
+            .find_map(|(offset, _)| self.match_at(line, offset))
+    }
+
+    /// The first and last matched values written one after the other, so single digits give
+    /// the puzzle's two-digit value and larger values (`twenty`) keep all their digits.
+    /// Lines with no match at all, or whose value would not fit in a `usize`, have no value.
+    pub fn calibration_value(&self, line: &str) -> Option<usize> {
+        concat(self.first(line)?.value, self.last(line)?.value)
+    }
+}
+
+/// `None` if the result does not fit in a `usize`.
+pub(crate) fn concat(first: usize, last: usize) -> Option<usize> {
+    let mut shift: usize = 10;
+    while shift <= last {
+        shift = shift.checked_mul(10)?;
+    }
+    first.checked_mul(shift)?.checked_add(last)
+}
+
+/// Parses one `token = value` pair per line; blank lines and `#` comments are skipped.
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Vocabulary::new();
+
+        for (index, source) in s.lines().enumerate() {
+            let line = index + 1;
+            let trimmed = source.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (text, value) = trimmed.split_once('=').ok_or_else(|| {
+                ParseError::at(
+                    Day01::DAY,
+                    line,
+                    source,
+                    trimmed,
+                    "expected `token = value`",
+                )
+            })?;
+            let (text, value) = (text.trim(), value.trim());
+            if text.is_empty() {
+                return Err(ParseError::at(
+                    Day01::DAY,
+                    line,
+                    source,
+                    trimmed,
+                    "empty token",
+                ));
+            }
+            let value = value.parse().map_err(|_| {
+                ParseError::at(Day01::DAY, line, source, value, "expected a number")
+            })?;
+
+            vocabulary.insert(text, value);
+        }
+
+        Ok(vocabulary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let vocabulary = Vocabulary::english();
+        let offsets = vocabulary
+            .matches("xtwone3four")
+            .map(|m| (m.token, m.offset))
+            .collect::<Vec<_>>();
+
+        assert_eq!(offsets, vec![("two", 1), ("one", 3), ("3", 6), ("four", 7)]);
+        assert_eq!(vocabulary.calibration_value("xtwone3four"), Some(24));
+        assert_eq!(vocabulary.calibration_value("abc"), None);
+
+        let big = Vocabulary::digits().with("big", usize::MAX);
+        assert_eq!(big.calibration_value("big"), None);
+        assert_eq!(big.calibration_value("1big"), None);
+        assert_eq!(concat(2, usize::MAX / 10), None);
+        assert_eq!(concat(1, 99), Some(199));
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let vocabulary = "# german
+eins = 1
+zwei = 2
+drei = 3
+zwanzig = 20"
+            .parse::<Vocabulary>()
+            .unwrap();
+
+        assert_eq!(vocabulary.calibration_value("xxzweiyydrei"), Some(23));
+        assert_eq!(vocabulary.calibration_value("zwanzig und eins"), Some(201));
+        assert_eq!(vocabulary.calibration_value("über drei"), Some(33));
+        assert_eq!(
+            "eins 1".parse::<Vocabulary>().err().map(|err| err.column),
+            Some(1)
+        );
+    }
+}
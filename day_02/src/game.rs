@@ -0,0 +1,379 @@
+use std::fmt::{self, Display};
+
+use aoc_core::{ParseError, Solution};
+
+use crate::Day02;
+
+/// Cube counts per colour, in the order the colours were first seen. Used both for a single
+/// draw and for the contents of a bag; a colour that is not listed counts as zero. Colour
+/// names borrow from the text they were parsed from.
+#[derive(Debug, Clone, Default)]
+pub struct Round<'a> {
+    counts: Vec<(&'a str, u32)>,
+}
+
+impl<'a> Round<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts
+            .iter()
+            .find(|(c, _)| *c == colour)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub fn set(&mut self, colour: &'a str, count: u32) {
+        match self.counts.iter_mut().find(|(c, _)| *c == colour) {
+            Some((_, c)) => *c = count,
+            None => self.counts.push((colour, count)),
+        }
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.counts.iter().map(|&(colour, _)| colour)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, u32)> + '_ {
+        self.counts.iter().copied()
+    }
+
+    pub fn is_possible(&self, bag: &Round) -> bool {
+        self.iter().all(|(colour, count)| count <= bag.get(colour))
+    }
+
+    /// The larger count of each colour across both rounds.
+    pub fn max(&self, other: &Round<'a>) -> Round<'a> {
+        let mut max = self.clone();
+        for (colour, count) in other.iter() {
+            if count > max.get(colour) {
+                max.set(colour, count);
+            }
+        }
+        max
+    }
+
+    /// The product of the counts of every colour in `palette`, so a colour that was never
+    /// drawn makes the power zero. `None` if it does not fit in a `u64`.
+    pub fn power(&self, palette: &[&str]) -> Option<u64> {
+        palette.iter().try_fold(1_u64, |power, colour| {
+            power.checked_mul(self.get(colour).into())
+        })
+    }
+
+    fn parse(line: usize, source: &str, round: &'a str) -> Result<Self, ParseError> {
+        let mut counts = Round::new();
+
+        for draw in round.split(", ") {
+            let (colour, count) = parse_draw(line, source, draw)?;
+            counts.set(colour, count);
+        }
+
+        Ok(counts)
+    }
+}
+
+/// Parses an unsigned decimal made only of ASCII digits, unlike `str::parse` which also
+/// accepts a leading `+`. On failure returns what was wrong with it.
+fn parse_number(number: &str) -> Result<u32, &'static str> {
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("expected a number");
+    }
+    number.parse().map_err(|_| "number out of range")
+}
+
+pub(crate) fn is_colour(colour: &str) -> bool {
+    !colour.is_empty() && colour.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn parse_draw<'a>(line: usize, source: &str, draw: &'a str) -> Result<(&'a str, u32), ParseError> {
+    let (count, colour) = draw.split_once(' ').ok_or_else(|| {
+        ParseError::at(
+            Day02::DAY,
+            line,
+            source,
+            draw,
+            "expected `<count> <colour>`",
+        )
+    })?;
+    let count = parse_number(count)
+        .map_err(|message| ParseError::at(Day02::DAY, line, source, draw, message))?;
+
+    if !is_colour(colour) {
+        return Err(ParseError::at(
+            Day02::DAY,
+            line,
+            source,
+            colour,
+            "expected a colour",
+        ));
+    }
+
+    Ok((colour, count))
+}
+
+/// Parses a bag such as `12 red, 13 green, 14 blue`. Counts may also be given one per line,
+/// as in a bag file.
+impl<'a> TryFrom<&'a str> for Round<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut round = Round::new();
+
+        for (index, source) in s.lines().enumerate() {
+            for draw in source.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+                let (colour, count) = parse_draw(index + 1, source, draw)?;
+                round.set(colour, count);
+            }
+        }
+
+        Ok(round)
+    }
+}
+
+impl Display for Round<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (colour, count)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rounds are equal when every colour has the same count, whatever order they were listed in.
+impl PartialEq for Round<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter()
+            .all(|(colour, count)| other.get(colour) == count)
+            && other
+                .iter()
+                .all(|(colour, count)| self.get(colour) == count)
+    }
+}
+
+impl Eq for Round<'_> {}
+
+impl<'a> FromIterator<(&'a str, u32)> for Round<'a> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, u32)>>(iter: I) -> Self {
+        let mut round = Round::new();
+        for (colour, count) in iter {
+            round.set(colour, count);
+        }
+        round
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<'a> {
+    pub id: u32,
+    rounds: Vec<Round<'a>>,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(id: u32, rounds: Vec<Round<'a>>) -> Self {
+        Self { id, rounds }
+    }
+
+    pub fn rounds(&self) -> &[Round<'a>] {
+        &self.rounds
+    }
+
+    pub fn is_possible(&self, bag: &Round) -> bool {
+        self.rounds.iter().all(|round| round.is_possible(bag))
+    }
+
+    pub fn get_power(&self, palette: &[&str]) -> Option<u64> {
+        self.minimal_bag().power(palette)
+    }
+
+    /// The fewest cubes of each colour that could have produced every round of this game.
+    pub fn minimal_bag(&self) -> Round<'a> {
+        self.rounds.iter().fold(Round::new(), |acc, b| acc.max(b))
+    }
+
+    /// Every colour this game draws more of than `bag` holds, with the largest draw.
+    pub fn violations(&self, bag: &Round) -> Vec<Violation<'a>> {
+        self.minimal_bag()
+            .iter()
+            .filter(|&(colour, drawn)| drawn > bag.get(colour))
+            .map(|(colour, drawn)| Violation {
+                colour,
+                limit: bag.get(colour),
+                drawn,
+            })
+            .collect()
+    }
+
+    fn parse(line: usize, source: &'a str) -> Result<Self, ParseError> {
+        let (id, rounds) = source
+            .strip_prefix("Game ")
+            .and_then(|rest| rest.split_once(": "))
+            .ok_or_else(|| {
+                ParseError::at(
+                    Day02::DAY,
+                    line,
+                    source,
+                    source,
+                    "expected `Game <id>: <rounds>`",
+                )
+            })?;
+        let id = parse_number(id)
+            .map_err(|message| ParseError::at(Day02::DAY, line, source, id, message))?;
+        let rounds = rounds
+            .split("; ")
+            .map(|round| Round::parse(line, source, round))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, rounds })
+    }
+}
+
+/// Writes the canonical `Game <id>: <rounds>` line, which parses back to the same game.
+impl Display for Game<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (index, round) in self.rounds.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", round)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a single `Game <id>: <rounds>` line.
+impl<'a> TryFrom<&'a str> for Game<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Game::parse(1, s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation<'a> {
+    pub colour: &'a str,
+    pub limit: u32,
+    pub drawn: u32,
+}
+
+impl Violation<'_> {
+    pub fn excess(&self) -> u32 {
+        self.drawn - self.limit
+    }
+}
+
+impl Display for Violation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} > {} (+{})",
+            self.colour,
+            self.drawn,
+            self.limit,
+            self.excess()
+        )
+    }
+}
+
+/// Every game in the input, along with the colours that appear anywhere in it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameLog<'a> {
+    games: Vec<Game<'a>>,
+    palette: Vec<&'a str>,
+}
+
+impl<'a> GameLog<'a> {
+    pub fn new(games: Vec<Game<'a>>) -> Self {
+        let mut palette: Vec<&str> = Vec::new();
+        for colour in games
+            .iter()
+            .flat_map(|game| game.rounds.iter().flat_map(Round::colours))
+        {
+            if !palette.contains(&colour) {
+                palette.push(colour);
+            }
+        }
+
+        Self { games, palette }
+    }
+
+    pub fn games(&self) -> &[Game<'a>] {
+        &self.games
+    }
+
+    pub fn game(&self, id: u32) -> Option<&Game<'a>> {
+        self.games.iter().find(|game| game.id == id)
+    }
+
+    /// A new log with only the games `keep` accepts.
+    pub fn filter(&self, mut keep: impl FnMut(&Game) -> bool) -> GameLog<'a> {
+        GameLog::new(
+            self.games
+                .iter()
+                .filter(|game| keep(game))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// The games that could have been played with `bag`.
+    pub fn feasible<'b>(&'b self, bag: &'b Round) -> impl Iterator<Item = &'b Game<'a>> + 'b {
+        self.games.iter().filter(move |game| game.is_possible(bag))
+    }
+
+    /// The smallest bag under which every game in `ids` is possible, or the first id that is
+    /// not in the log.
+    pub fn smallest_bag(&self, ids: &[u32]) -> Result<Round<'a>, u32> {
+        ids.iter().try_fold(Round::new(), |bag, &id| {
+            let game = self.game(id).ok_or(id)?;
+            Ok(bag.max(&game.minimal_bag()))
+        })
+    }
+
+    /// The sum of every game's power over the log's palette, or the index of the first game at
+    /// which the total no longer fits in a `u64`.
+    pub fn total_power(&self) -> Result<u64, usize> {
+        self.games
+            .iter()
+            .enumerate()
+            .try_fold(0_u64, |total, (index, game)| {
+                game.get_power(&self.palette)
+                    .and_then(|power| total.checked_add(power))
+                    .ok_or(index)
+            })
+    }
+
+    /// The colours seen in the log, in order of first appearance.
+    pub fn palette(&self) -> &[&'a str] {
+        &self.palette
+    }
+
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let games = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Game::parse(index + 1, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::new(games))
+    }
+}
+
+/// One game per line, in the same format as the puzzle input.
+impl Display for GameLog<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, game) in self.games.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", game)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+mod game;
+mod serialize;
+mod stats;
+
+use aoc_core::{ParseError, Solution};
+
+pub use game::{Game, GameLog, Round, Violation};
+pub use stats::{Distribution, Outlier};
+
+/// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+pub fn default_bag() -> Round<'static> {
+    Round::from_iter([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+/// The sum of the ids of every game that could have been played with `bag`.
+pub fn possible_id_sum(log: &GameLog, bag: &Round) -> u64 {
+    log.feasible(bag).map(|game| u64::from(game.id)).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = GameLog<'a>;
+    type Answer = u64;
+
+    /// Also rejects logs whose total power overflows, so `part_02` cannot.
+    fn parse(input: &str) -> Result<GameLog<'_>, ParseError> {
+        let log = GameLog::parse(input)?;
+        if let Err(index) = log.total_power() {
+            // Games are parsed one per line, so a game's index is its line number.
+            let line = input.lines().nth(index).unwrap_or_default();
+            return Err(ParseError::new(
+                Day02::DAY,
+                index + 1,
+                1,
+                line,
+                "total power out of range",
+            ));
+        }
+        Ok(log)
+    }
+
+    fn part_01(log: &GameLog) -> u64 {
+        possible_id_sum(log, &default_bag())
+    }
+
+    fn part_02(log: &GameLog) -> u64 {
+        log.total_power().expect("checked in parse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_01() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(Day02::part_01(&Day02::parse(input).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part_02() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(Day02::part_02(&Day02::parse(input).unwrap()), 2286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, two green";
+
+        let err = Day02::parse(input).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 17));
+        assert_eq!(err.text, "two green");
+    }
+
+    #[test]
+    fn test_any_palette() {
+        let input = "Game 1: 3 cyan, 4 magenta; 1 yellow
+Game 2: 1 cyan, 9 magenta, 2 yellow
+Game 3: 2 magenta";
+        let log = Day02::parse(input).unwrap();
+        let bag = Round::from_iter([("cyan", 3), ("magenta", 5), ("yellow", 2)]);
+
+        assert_eq!(log.palette(), ["cyan", "magenta", "yellow"]);
+        assert!(log.games()[0].is_possible(&bag));
+        assert!(!log.games()[1].is_possible(&bag));
+        assert_eq!(log.games()[0].get_power(log.palette()), Some(12));
+        assert_eq!(log.games()[2].get_power(log.palette()), Some(0));
+    }
+
+    #[test]
+    fn test_large_palette() {
+        let input = "Game 1: 20 a, 20 b, 20 c, 20 d, 20 e, 20 f, 20 g, 20 h";
+        assert_eq!(Day02::part_02(&Day02::parse(input).unwrap()), 20_u64.pow(8));
+
+        let input = "Game 1: 3 a
+Game 2: 4000000000 a, 4000000000 b, 4000000000 c";
+        let err = Day02::parse(input).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "total power out of range");
+    }
+
+    #[test]
+    fn test_violations() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let log = Day02::parse(input).unwrap();
+        let bag = "12 red,\n14 blue, 13 green".try_into().unwrap();
+
+        assert_eq!(bag, default_bag());
+        assert!(log.games()[0].violations(&bag).is_empty());
+        assert_eq!(
+            log.games()[1]
+                .violations(&bag)
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            vec!["red 20 > 12 (+8)"]
+        );
+        assert_eq!(
+            possible_id_sum(&log, &Round::try_from("20 red, 13 green, 6 blue").unwrap()),
+            4
+        );
+    }
+
+    #[test]
+    fn test_bag_queries() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let log = Day02::parse(input).unwrap();
+
+        assert_eq!(
+            log.games()[0].minimal_bag(),
+            Round::try_from("4 red, 2 green, 6 blue").unwrap()
+        );
+        assert_eq!(
+            log.smallest_bag(&[1, 2]),
+            Ok(Round::try_from("4 red, 3 green, 6 blue").unwrap())
+        );
+        assert_eq!(log.smallest_bag(&[1, 7]), Err(7));
+
+        let bag = log.smallest_bag(&[1, 2]).unwrap();
+        assert_eq!(
+            log.feasible(&bag).map(|game| game.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_borrowed_parse() {
+        let line = String::from("Game 12: 3 blue, 4 red; 1 red");
+        let game = Game::try_from(line.as_str()).unwrap();
+        assert_eq!(game.id, 12);
+        assert_eq!(game.rounds().len(), 2);
+        assert!(std::ptr::eq(
+            game.rounds()[0].colours().next().unwrap(),
+            &line[11..15]
+        ));
+
+        for (line, column) in [
+            ("Game +1: 3 blue", 6),
+            ("Game 1: +3 blue", 9),
+            ("Game 1: 3 blue,4 red", 11),
+            ("Game 1 3 blue", 1),
+            ("Game 99999999999: 3 blue", 6),
+        ] {
+            let err = Game::try_from(line).err().unwrap();
+            assert_eq!(err.column, column, "{}", line);
+        }
+    }
+}
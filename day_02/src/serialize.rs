@@ -0,0 +1,163 @@
+use std::fmt;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::game::{is_colour, Game, GameLog, Round};
+
+/// A round is a map from colour to count, in the order the colours were drawn.
+impl Serialize for Round<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (colour, count) in self.iter() {
+            map.serialize_entry(colour, &count)?;
+        }
+        map.end()
+    }
+}
+
+struct RoundVisitor;
+
+impl<'de> Visitor<'de> for RoundVisitor {
+    type Value = Round<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map from colour to count")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Round<'de>, A::Error> {
+        let mut round = Round::new();
+        while let Some((colour, count)) = map.next_entry::<&str, u32>()? {
+            if !is_colour(colour) {
+                return Err(de::Error::custom(format!("invalid colour `{}`", colour)));
+            }
+            round.set(colour, count);
+        }
+        if round.colours().next().is_none() {
+            return Err(de::Error::custom("a round must draw at least one colour"));
+        }
+        Ok(round)
+    }
+}
+
+/// Colours are borrowed from the serialized text, so they cannot contain escapes.
+impl<'de: 'a, 'a> Deserialize<'de> for Round<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(RoundVisitor)
+    }
+}
+
+impl Serialize for Game<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut game = serializer.serialize_struct("Game", 2)?;
+        game.serialize_field("id", &self.id)?;
+        game.serialize_field("rounds", self.rounds())?;
+        game.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawGame<'a> {
+    id: u32,
+    #[serde(borrow)]
+    rounds: Vec<Round<'a>>,
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for Game<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawGame::deserialize(deserializer)?;
+        if raw.rounds.is_empty() {
+            return Err(de::Error::custom(format!("game {} has no rounds", raw.id)));
+        }
+        Ok(Game::new(raw.id, raw.rounds))
+    }
+}
+
+/// A log is just its list of games; the palette is rebuilt on import.
+impl Serialize for GameLog<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.games())
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for GameLog<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(GameLog::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOURS: [&str; 6] = ["red", "green", "blue", "cyan", "dark_red", "x9"];
+
+    /// Deterministic pseudo-random logs, with repeated ids and colours in any order.
+    fn logs() -> Vec<GameLog<'static>> {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut next = move |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        (0..200)
+            .map(|_| {
+                let games = (0..next(6))
+                    .map(|_| {
+                        let rounds = (0..next(4) + 1)
+                            .map(|_| {
+                                (0..next(5) + 1)
+                                    .map(|_| {
+                                        let colour = COLOURS[next(COLOURS.len() as u64) as usize];
+                                        (colour, next(30) as u32)
+                                    })
+                                    .collect()
+                            })
+                            .collect();
+                        Game::new(next(200) as u32, rounds)
+                    })
+                    .collect();
+                GameLog::new(games)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        for log in logs() {
+            let text = log.to_string();
+            assert_eq!(GameLog::parse(&text).unwrap(), log, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        for log in logs() {
+            let json = serde_json::to_string(&log).unwrap();
+            let imported: GameLog = serde_json::from_str(&json).unwrap();
+            assert_eq!(imported, log, "{}", json);
+            assert_eq!(imported.palette(), log.palette());
+        }
+    }
+
+    #[test]
+    fn test_json_format() {
+        let log = GameLog::parse("Game 1: 3 blue, 4 red; 2 green").unwrap();
+        assert_eq!(
+            serde_json::to_string(&log).unwrap(),
+            r#"[{"id":1,"rounds":[{"blue":3,"red":4},{"green":2}]}]"#
+        );
+
+        for json in [
+            r#"[{"id":1,"rounds":[]}]"#,
+            r#"[{"id":1,"rounds":[{}]}]"#,
+            r#"[{"id":1,"rounds":[{"light blue":1}]}]"#,
+        ] {
+            assert!(serde_json::from_str::<GameLog>(json).is_err(), "{}", json);
+        }
+    }
+}
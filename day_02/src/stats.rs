@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use crate::game::{GameLog, Round};
+
+/// How many cubes of one colour were drawn per round, over every round in a log. A round that
+/// does not mention the colour drew zero of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distribution<'a> {
+    pub colour: &'a str,
+    /// The number of rounds that drew each count, for every count drawn at least once.
+    pub histogram: BTreeMap<u32, usize>,
+}
+
+impl Distribution<'_> {
+    pub fn rounds(&self) -> usize {
+        self.histogram.values().sum()
+    }
+
+    pub fn max(&self) -> Option<u32> {
+        self.histogram.keys().next_back().copied()
+    }
+
+    pub fn mean(&self) -> f64 {
+        let total: f64 = self
+            .histogram
+            .iter()
+            .map(|(&count, &rounds)| f64::from(count) * rounds as f64)
+            .sum();
+        total / self.rounds() as f64
+    }
+
+    /// Population standard deviation.
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        let squares: f64 = self
+            .histogram
+            .iter()
+            .map(|(&count, &rounds)| (f64::from(count) - mean).powi(2) * rounds as f64)
+            .sum();
+        (squares / self.rounds() as f64).sqrt()
+    }
+
+    /// The lower median count.
+    pub fn median(&self) -> Option<u32> {
+        let middle = self.rounds().checked_sub(1)? / 2;
+        let mut seen = 0;
+        self.histogram
+            .iter()
+            .find(|&(_, &rounds)| {
+                seen += rounds;
+                seen > middle
+            })
+            .map(|(&count, _)| count)
+    }
+}
+
+impl Display for Distribution<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: mean {:.2}, std dev {:.2}, median {}, max {} over {} rounds",
+            self.colour,
+            self.mean(),
+            self.std_dev(),
+            self.median().unwrap_or(0),
+            self.max().unwrap_or(0),
+            self.rounds()
+        )
+    }
+}
+
+/// A game whose largest draw of `colour` is unusually high compared with the other games.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlier<'a> {
+    pub id: u32,
+    pub colour: &'a str,
+    pub drawn: u32,
+    /// Standard deviations above the mean largest draw across all games.
+    pub z_score: f64,
+}
+
+impl Display for Outlier<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: {} {} (z = {:.2})",
+            self.id, self.colour, self.drawn, self.z_score
+        )
+    }
+}
+
+impl<'a> GameLog<'a> {
+    /// One distribution per colour, in palette order.
+    pub fn distributions(&self) -> Vec<Distribution<'a>> {
+        self.palette()
+            .iter()
+            .map(|&colour| {
+                let mut histogram = BTreeMap::new();
+                for round in self.games().iter().flat_map(|game| game.rounds()) {
+                    *histogram.entry(round.get(colour)).or_default() += 1;
+                }
+                Distribution { colour, histogram }
+            })
+            .collect()
+    }
+
+    /// The bag that makes the observed draws most likely, assuming each round draws a number
+    /// of each colour uniformly between zero and what the bag holds. A bigger bag only spreads
+    /// the same probability over more outcomes, so the estimate is the largest draw of each
+    /// colour: the smallest bag under which every game is possible.
+    pub fn maximum_likelihood_bag(&self) -> Round<'a> {
+        self.games()
+            .iter()
+            .fold(Round::new(), |bag, game| bag.max(&game.minimal_bag()))
+    }
+
+    /// Natural log of the probability of every observed draw given `bag`, under the model of
+    /// [`GameLog::maximum_likelihood_bag`]. Negative infinity if any game is impossible.
+    pub fn log_likelihood(&self, bag: &Round) -> f64 {
+        let rounds = self.games().iter().flat_map(|game| game.rounds());
+        rounds
+            .map(|round| {
+                if !round.is_possible(bag) {
+                    return f64::NEG_INFINITY;
+                }
+                self.palette()
+                    .iter()
+                    .map(|&colour| -(f64::from(bag.get(colour)) + 1.0).ln())
+                    .sum()
+            })
+            .sum()
+    }
+
+    /// Games whose largest draw of some colour lies more than `threshold` standard deviations
+    /// above the mean largest draw of that colour across all games, most extreme first.
+    pub fn outliers(&self, threshold: f64) -> Vec<Outlier<'a>> {
+        let bags = self
+            .games()
+            .iter()
+            .map(|game| (game.id, game.minimal_bag()))
+            .collect::<Vec<_>>();
+        let mut outliers = Vec::new();
+
+        for &colour in self.palette() {
+            let drawn = bags.iter().map(|(_, bag)| f64::from(bag.get(colour)));
+            let mean = drawn.clone().sum::<f64>() / bags.len() as f64;
+            let std_dev =
+                (drawn.map(|d| (d - mean).powi(2)).sum::<f64>() / bags.len() as f64).sqrt();
+            if std_dev == 0.0 {
+                continue;
+            }
+
+            for (id, bag) in &bags {
+                let drawn = bag.get(colour);
+                let z_score = (f64::from(drawn) - mean) / std_dev;
+                if z_score > threshold {
+                    outliers.push(Outlier {
+                        id: *id,
+                        colour,
+                        drawn,
+                        z_score,
+                    });
+                }
+            }
+        }
+
+        outliers.sort_by(|a, b| b.z_score.total_cmp(&a.z_score));
+        outliers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_distributions() {
+        let log = GameLog::parse(INPUT).unwrap();
+        let distributions = log.distributions();
+
+        let blue = &distributions[0];
+        assert_eq!(blue.colour, "blue");
+        assert_eq!(blue.rounds(), 14);
+        assert_eq!(blue.max(), Some(15));
+        assert_eq!(blue.median(), Some(2));
+        assert_eq!(blue.histogram[&0], 3);
+        assert!((blue.mean() - 50.0 / 14.0).abs() < 1e-9);
+
+        let single = Distribution {
+            colour: "red",
+            histogram: BTreeMap::from([(0, 1), (2, 1)]),
+        };
+        assert_eq!(single.std_dev(), 1.0);
+
+        let log = GameLog::parse("Game 1: 4000000000 red; 1 red").unwrap();
+        let red = &log.distributions()[0];
+        assert_eq!(red.max(), Some(4_000_000_000));
+        assert_eq!(red.median(), Some(1));
+        assert_eq!(red.mean(), 2_000_000_000.5);
+    }
+
+    #[test]
+    fn test_maximum_likelihood_bag() {
+        let log = GameLog::parse(INPUT).unwrap();
+        let bag = log.maximum_likelihood_bag();
+        assert_eq!(bag, Round::try_from("20 red, 13 green, 15 blue").unwrap());
+
+        let best = log.log_likelihood(&bag);
+        for (colour, extra) in [("red", 1), ("green", 3), ("blue", 1)] {
+            let mut bigger = bag.clone();
+            bigger.set(colour, bag.get(colour) + extra);
+            assert!(log.log_likelihood(&bigger) < best);
+        }
+
+        let mut smaller = bag.clone();
+        smaller.set("red", 19);
+        assert_eq!(log.log_likelihood(&smaller), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let log = GameLog::parse(INPUT).unwrap();
+        let outliers = log.outliers(1.75);
+
+        assert_eq!(
+            outliers
+                .iter()
+                .map(|o| (o.id, o.colour, o.drawn))
+                .collect::<Vec<_>>(),
+            vec![(3, "green", 13), (4, "blue", 15)]
+        );
+        assert!(log.outliers(3.0).is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+mod render;
+mod rule;
+mod schematic;
+mod token;
+
+use aoc_core::{ParseError, Solution};
+
+pub use render::Highlight;
+pub use rule::{Aggregate, Count, Gear, GearRule};
+pub use schematic::{Part, Schematic, Symbol};
+pub use token::{Cell, Token, Tokenizer};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+    type Answer = usize;
+
+    /// Also rejects schematics whose gear ratios overflow, so `part_02` cannot.
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        let schematic = Schematic::parse(input, &Tokenizer::default())?;
+        if let Err((x, y)) = schematic.gear_sum(&GearRule::default()) {
+            return Err(ParseError::new(
+                Day03::DAY,
+                y + 1,
+                x + 1,
+                input.lines().nth(y).unwrap_or_default(),
+                "gear ratio out of range",
+            ));
+        }
+        Ok(schematic)
+    }
+
+    fn part_01(schematic: &Schematic) -> usize {
+        schematic.part_numbers().map(|part| part.value).sum()
+    }
+
+    fn part_02(schematic: &Schematic) -> usize {
+        schematic
+            .gear_sum(&GearRule::default())
+            .expect("checked in parse")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_01() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        assert_eq!(Day03::part_01(&Day03::parse(input).unwrap()), 4361);
+    }
+
+    #[test]
+    fn test_part_02() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        assert_eq!(Day03::part_02(&Day03::parse(input).unwrap()), 467835);
+    }
+
+    #[test]
+    fn test_edges() {
+        let input = "1.2
+.#.
+3.4";
+        assert_eq!(Day03::part_01(&Day03::parse(input).unwrap()), 10);
+
+        let err = Day03::parse("12.\n#.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Day03::parse("9999999999.\n*..........\n9999999999.")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "gear ratio out of range");
+
+        let input = "12_
+...";
+        let underscore = Tokenizer::new().with_symbol('_');
+        assert_eq!(Day03::part_01(&Day03::parse(input).unwrap()), 0);
+        assert_eq!(
+            Day03::part_01(&Schematic::parse(input, &underscore).unwrap()),
+            12
+        );
+    }
+
+    #[test]
+    fn test_shared_part() {
+        let input = "2...
+.*..
+..3*
+...4";
+        let schematic = Day03::parse(input).unwrap();
+        let three = &schematic.parts()[1];
+        assert_eq!(
+            three.adjacent_symbols(&schematic).collect::<Vec<_>>(),
+            vec![((1, 1), '*'), ((3, 2), '*')]
+        );
+        assert_eq!(Day03::part_02(&schematic), 2 * 3 + 3 * 4);
+    }
+}
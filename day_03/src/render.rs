@@ -0,0 +1,190 @@
+use std::fmt::Write;
+
+use aoc_core::Grid;
+
+use crate::{GearRule, Schematic};
+
+/// How a schematic cell is shown when rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    Plain,
+    /// A digit of a number touching a symbol.
+    Counted,
+    /// A digit of a number touching no symbol.
+    Uncounted,
+    /// A symbol matching the gear rule.
+    Gear,
+    Symbol,
+}
+
+impl Highlight {
+    fn ansi(&self) -> &'static str {
+        match self {
+            Highlight::Plain => "\x1b[2m",
+            Highlight::Counted => "\x1b[32m",
+            Highlight::Uncounted => "\x1b[31m",
+            Highlight::Gear => "\x1b[1;33m",
+            Highlight::Symbol => "\x1b[36m",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            Highlight::Plain => "plain",
+            Highlight::Counted => "counted",
+            Highlight::Uncounted => "uncounted",
+            Highlight::Gear => "gear",
+            Highlight::Symbol => "symbol",
+        }
+    }
+}
+
+const STYLE: &str = "body { background: #1e1e1e; color: #d4d4d4; }
+pre { font: 14px/1.2 monospace; }
+.plain { color: #5a5a5a; }
+.counted { color: #6a9955; }
+.uncounted { color: #f14c4c; }
+.gear { color: #ffd700; font-weight: bold; }
+.symbol { color: #4fc1ff; }";
+
+impl Schematic {
+    /// The highlight of every cell, with gears picked out by `rule`.
+    pub fn highlights(&self, rule: &GearRule) -> Grid<Highlight> {
+        let mut highlights = self.grid().map(|_| Highlight::Plain);
+
+        for (index, part) in self.parts().iter().enumerate() {
+            let highlight = if self.is_part_number(index) {
+                Highlight::Counted
+            } else {
+                Highlight::Uncounted
+            };
+            let (x, y) = part.anchor;
+            for x in x..x + part.width {
+                highlights[(x, y)] = highlight;
+            }
+        }
+        for &(pos, _) in self.symbols() {
+            highlights[pos] = Highlight::Symbol;
+        }
+        for gear in self.gears(rule) {
+            highlights[gear.pos] = Highlight::Gear;
+        }
+
+        highlights
+    }
+
+    /// The schematic with ANSI colours: counted parts green, uncounted numbers red, gears
+    /// bold yellow, other symbols cyan and empty space dimmed.
+    pub fn render_ansi(&self, rule: &GearRule) -> String {
+        let mut out = String::new();
+        for (row, highlights) in self.runs(rule) {
+            for (highlight, text) in highlights {
+                write!(out, "{}{}\x1b[0m", highlight.ansi(), text).unwrap();
+            }
+            if row + 1 < self.grid().height() {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// A standalone HTML page with the same colouring as [`Schematic::render_ansi`].
+    pub fn render_html(&self, rule: &GearRule) -> String {
+        let mut out = String::new();
+        writeln!(out, "<!DOCTYPE html>").unwrap();
+        writeln!(out, "<html>\n<head>\n<meta charset=\"utf-8\">").unwrap();
+        writeln!(out, "<title>Engine schematic</title>").unwrap();
+        writeln!(out, "<style>\n{}\n</style>\n</head>\n<body>", STYLE).unwrap();
+        write!(out, "<pre>").unwrap();
+        for (row, highlights) in self.runs(rule) {
+            if row > 0 {
+                out.push('\n');
+            }
+            for (highlight, text) in highlights {
+                write!(
+                    out,
+                    "<span class=\"{}\">{}</span>",
+                    highlight.class(),
+                    escape(&text)
+                )
+                .unwrap();
+            }
+        }
+        writeln!(out, "</pre>\n</body>\n</html>").unwrap();
+        out
+    }
+
+    /// Each row split into runs of cells sharing a highlight.
+    fn runs(&self, rule: &GearRule) -> Vec<(usize, Vec<(Highlight, String)>)> {
+        let highlights = self.highlights(rule);
+        self.grid()
+            .rows()
+            .zip(highlights.rows())
+            .enumerate()
+            .map(|(y, (cells, highlights))| {
+                let mut runs: Vec<(Highlight, String)> = Vec::new();
+                for (&c, &highlight) in cells.iter().zip(highlights) {
+                    match runs.last_mut() {
+                        Some((last, text)) if *last == highlight => text.push(c),
+                        _ => runs.push((highlight, c.to_string())),
+                    }
+                }
+                (y, runs)
+            })
+            .collect()
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tokenizer;
+
+    const INPUT: &str = "12*3
+...&
+9<..";
+
+    #[test]
+    fn test_highlights() {
+        let schematic = Schematic::parse(INPUT, &Tokenizer::default()).unwrap();
+        let highlights = schematic.highlights(&GearRule::default());
+
+        assert_eq!(highlights[(0, 0)], Highlight::Counted);
+        assert_eq!(highlights[(2, 0)], Highlight::Gear);
+        assert_eq!(highlights[(3, 1)], Highlight::Symbol);
+        assert_eq!(highlights[(0, 2)], Highlight::Counted);
+        assert_eq!(highlights[(2, 2)], Highlight::Plain);
+
+        let schematic = Schematic::parse("7..\n..1", &Tokenizer::default()).unwrap();
+        let highlights = schematic.highlights(&GearRule::default());
+        assert_eq!(highlights[(0, 0)], Highlight::Uncounted);
+    }
+
+    #[test]
+    fn test_render() {
+        let schematic = Schematic::parse(INPUT, &Tokenizer::default()).unwrap();
+
+        let ansi = schematic.render_ansi(&GearRule::default());
+        assert!(ansi.starts_with("\x1b[32m12\x1b[0m\x1b[1;33m*\x1b[0m\x1b[32m3\x1b[0m\n"));
+        assert_eq!(ansi.lines().count(), 3);
+
+        let html = schematic.render_html(&GearRule::default());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<span class=\"symbol\">&lt;</span>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+}
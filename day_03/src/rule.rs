@@ -0,0 +1,234 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use aoc_core::Point;
+
+use crate::{Part, Schematic, Symbol};
+
+/// How many parts must touch a symbol for it to count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Count {
+    pub fn matches(&self, parts: usize) -> bool {
+        match *self {
+            Count::Exactly(n) => parts == n,
+            Count::AtLeast(n) => parts >= n,
+            Count::AtMost(n) => parts <= n,
+        }
+    }
+}
+
+/// Parses `2` or `=2`, `>=2` and `<=2`.
+impl FromStr for Count {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (count, n): (fn(usize) -> Count, &str) = if let Some(n) = s.strip_prefix(">=") {
+            (Count::AtLeast, n)
+        } else if let Some(n) = s.strip_prefix("<=") {
+            (Count::AtMost, n)
+        } else {
+            (Count::Exactly, s.strip_prefix('=').unwrap_or(s))
+        };
+        n.parse()
+            .map(count)
+            .map_err(|_| format!("expected `N`, `>=N` or `<=N`, found `{}`", s))
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Count::Exactly(n) => write!(f, "={}", n),
+            Count::AtLeast(n) => write!(f, ">={}", n),
+            Count::AtMost(n) => write!(f, "<={}", n),
+        }
+    }
+}
+
+/// How the values of the parts around a matching symbol combine into its score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    /// Combines `values`, or `None` if the result does not fit in a `usize`. No values at all
+    /// score 0 whatever the aggregate, so a symbol with no parts around it never adds to a total.
+    pub fn apply(&self, values: impl Iterator<Item = usize>) -> Option<usize> {
+        let mut values = values.peekable();
+        if values.peek().is_none() {
+            return Some(0);
+        }
+
+        match self {
+            Aggregate::Product => values.try_fold(1_usize, usize::checked_mul),
+            Aggregate::Sum => values.try_fold(0_usize, usize::checked_add),
+            Aggregate::Max => values.max(),
+        }
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Aggregate::Product),
+            "sum" => Ok(Aggregate::Sum),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(format!("expected `product`, `sum` or `max`, found `{}`", s)),
+        }
+    }
+}
+
+/// Which symbols count as gears and how their adjacent parts are scored. The puzzle's rule is
+/// [`GearRule::default`]: a `*` touching exactly two parts scores their product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbol: Symbol,
+    pub count: Count,
+    pub aggregate: Aggregate,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        Self::new('*')
+    }
+}
+
+impl GearRule {
+    /// `symbol` touching exactly two parts, scored by their product.
+    pub fn new(symbol: Symbol) -> Self {
+        Self {
+            symbol,
+            count: Count::Exactly(2),
+            aggregate: Aggregate::Product,
+        }
+    }
+
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+}
+
+/// A symbol that satisfied a [`GearRule`], with the parts around it and its score, which is
+/// `None` if it does not fit in a `usize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear<'s> {
+    pub pos: Point,
+    pub parts: Vec<&'s Part>,
+    pub score: Option<usize>,
+}
+
+impl Schematic {
+    /// Every symbol matching `rule`, in reading order.
+    pub fn gears(&self, rule: &GearRule) -> Vec<Gear<'_>> {
+        self.symbols()
+            .iter()
+            .enumerate()
+            .filter(|&(index, &(_, symbol))| {
+                symbol == rule.symbol && rule.count.matches(self.parts_of(index).len())
+            })
+            .map(|(index, &(pos, _))| {
+                let parts = self
+                    .parts_of(index)
+                    .iter()
+                    .map(|&part| &self.parts()[part])
+                    .collect::<Vec<_>>();
+                Gear {
+                    pos,
+                    score: rule.aggregate.apply(parts.iter().map(|part| part.value)),
+                    parts,
+                }
+            })
+            .collect()
+    }
+
+    /// The total score of every symbol matching `rule`, or the position of the first gear at
+    /// which the total no longer fits in a `usize`.
+    pub fn gear_sum(&self, rule: &GearRule) -> Result<usize, Point> {
+        self.gears(rule).iter().try_fold(0_usize, |total, gear| {
+            gear.score
+                .and_then(|score| total.checked_add(score))
+                .ok_or(gear.pos)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules() {
+        let schematic = Schematic::parse(
+            "2..5
+.*..
+..3#
+...4",
+            &Default::default(),
+        )
+        .unwrap();
+
+        assert_eq!(schematic.gear_sum(&GearRule::default()), Ok(2 * 3));
+        assert_eq!(
+            schematic.gear_sum(
+                &GearRule::new('*')
+                    .count(Count::AtLeast(2))
+                    .aggregate(Aggregate::Sum)
+            ),
+            Ok(2 + 3)
+        );
+        assert_eq!(
+            schematic.gear_sum(
+                &GearRule::new('#')
+                    .count(Count::AtMost(2))
+                    .aggregate(Aggregate::Max)
+            ),
+            Ok(4)
+        );
+        assert!(schematic.gears(&GearRule::new('$')).is_empty());
+
+        let isolated = Schematic::parse("*...\n....\n..*.", &Default::default()).unwrap();
+        let rule = GearRule::new('*').count(Count::AtMost(1));
+        assert_eq!(isolated.gears(&rule).len(), 2);
+        assert_eq!(isolated.gear_sum(&rule), Ok(0));
+
+        let large =
+            Schematic::parse("9999999999.\n*..........\n9999999999.", &Default::default()).unwrap();
+        let gears = large.gears(&GearRule::default());
+        assert_eq!(gears.len(), 1);
+        assert_eq!(gears[0].score, None);
+        assert_eq!(large.gear_sum(&GearRule::default()), Err((0, 1)));
+        assert_eq!(
+            large.gear_sum(&GearRule::default().aggregate(Aggregate::Sum)),
+            Ok(2 * 9999999999)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("2".parse(), Ok(Count::Exactly(2)));
+        assert_eq!(">=3".parse(), Ok(Count::AtLeast(3)));
+        assert_eq!(
+            "<=1".parse::<Count>().map(|c| c.to_string()),
+            Ok("<=1".into())
+        );
+        assert!("2+".parse::<Count>().is_err());
+        assert_eq!("max".parse(), Ok(Aggregate::Max));
+    }
+}
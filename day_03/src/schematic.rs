@@ -0,0 +1,169 @@
+use aoc_core::{Grid, ParseError, Point, Solution};
+
+use crate::{Day03, Token, Tokenizer};
+
+pub type Symbol = char;
+
+/// A number in the schematic, `width` cells wide starting at `anchor`. `index` is its place
+/// in [`Schematic::parts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub index: usize,
+    pub width: usize,
+    pub value: usize,
+    pub anchor: Point,
+}
+
+impl Part {
+    /// Every symbol touching this part, including diagonally, row by row.
+    pub fn adjacent_symbols<'s>(
+        &self,
+        schematic: &'s Schematic,
+    ) -> impl Iterator<Item = (Point, Symbol)> + 's {
+        schematic
+            .symbols_of(self.index)
+            .iter()
+            .map(|&index| schematic.symbols[index])
+    }
+}
+
+/// A parsed engine schematic: its parts, its symbols and which of them touch. Parts and
+/// symbols are both in reading order and are referred to by their index.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    grid: Grid<char>,
+    parts: Vec<Part>,
+    symbols: Vec<(Point, Symbol)>,
+    symbol_at: Grid<Option<usize>>,
+    part_symbols: Vec<Vec<usize>>,
+    symbol_parts: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    /// Parses a schematic, using `tokenizer` to decide which characters are symbols.
+    pub fn parse(input: &str, tokenizer: &Tokenizer) -> Result<Self, ParseError> {
+        let grid = Grid::parse(Day03::DAY, input, Some)?;
+        let mut symbol_at = grid.map(|_| None);
+        let mut parts = Vec::new();
+        let mut symbols = Vec::new();
+
+        for token in tokenizer.tokenize(&grid)? {
+            match token {
+                Token::Number {
+                    anchor,
+                    width,
+                    value,
+                } => parts.push(Part {
+                    index: parts.len(),
+                    width,
+                    value,
+                    anchor,
+                }),
+                Token::Symbol { pos, symbol } => {
+                    symbol_at[pos] = Some(symbols.len());
+                    symbols.push((pos, symbol));
+                }
+            }
+        }
+
+        let mut symbol_parts = vec![Vec::new(); symbols.len()];
+        let part_symbols = parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                let adjacent = symbol_at
+                    .surrounding(part.anchor, part.width)
+                    .filter_map(|pos| symbol_at[pos])
+                    .collect::<Vec<_>>();
+                for &symbol in &adjacent {
+                    symbol_parts[symbol].push(index);
+                }
+                adjacent
+            })
+            .collect();
+
+        Ok(Schematic {
+            grid,
+            parts,
+            symbols,
+            symbol_at,
+            part_symbols,
+            symbol_parts,
+        })
+    }
+
+    /// The schematic's text, one character per cell.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    pub fn symbols(&self) -> &[(Point, Symbol)] {
+        &self.symbols
+    }
+
+    /// The index of the symbol at `pos`, if there is one.
+    pub fn symbol_at(&self, pos: Point) -> Option<usize> {
+        self.symbol_at.get(pos).copied().flatten()
+    }
+
+    /// Indices of the symbols touching part `part`.
+    pub fn symbols_of(&self, part: usize) -> &[usize] {
+        &self.part_symbols[part]
+    }
+
+    /// Indices of the parts touching symbol `symbol`.
+    pub fn parts_of(&self, symbol: usize) -> &[usize] {
+        &self.symbol_parts[symbol]
+    }
+
+    /// Whether part `part` touches any symbol, and so counts as a part number.
+    pub fn is_part_number(&self, part: usize) -> bool {
+        !self.part_symbols[part].is_empty()
+    }
+
+    /// The parts that touch at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Part> + '_ {
+        self.parts
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| self.is_part_number(index))
+            .map(|(_, part)| part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacency() {
+        let schematic = Schematic::parse(
+            "12.*
+..3.
+#..7",
+            &Tokenizer::default(),
+        )
+        .unwrap();
+
+        let values = schematic.parts().iter().map(|part| part.value);
+        assert_eq!(values.collect::<Vec<_>>(), vec![12, 3, 7]);
+        assert_eq!(schematic.symbols(), &[((3, 0), '*'), ((0, 2), '#')]);
+        assert_eq!(schematic.symbol_at((3, 0)), Some(0));
+        assert_eq!(schematic.symbol_at((9, 9)), None);
+
+        assert_eq!(schematic.symbols_of(1), &[0]);
+        assert_eq!(schematic.parts_of(0), &[1]);
+        assert!(schematic.parts_of(1).is_empty());
+        assert_eq!(
+            schematic
+                .part_numbers()
+                .map(|part| part.value)
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use aoc_core::{Grid, ParseError, Point, Solution};
+
+use crate::Day03;
+
+/// What a single schematic character stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    Symbol(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    /// A run of digits read left to right, starting at `anchor`.
+    Number {
+        anchor: Point,
+        width: usize,
+        value: usize,
+    },
+    Symbol {
+        pos: Point,
+        symbol: char,
+    },
+}
+
+/// Splits a schematic into numbers and symbols. By default `.`, letters and `_` are empty
+/// space, ASCII digits make up numbers and everything else is a symbol; either class can be
+/// extended with specific characters.
+#[derive(Debug, Clone)]
+pub struct Tokenizer {
+    empty: Vec<char>,
+    symbols: Vec<char>,
+}
+
+impl Default for Tokenizer {
+    fn default() -> Self {
+        Self {
+            empty: vec!['.'],
+            symbols: Vec::new(),
+        }
+    }
+}
+
+impl Tokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treats `c` as a symbol, even if it would otherwise be empty space.
+    pub fn with_symbol(mut self, c: char) -> Self {
+        self.empty.retain(|&e| e != c);
+        self.symbols.push(c);
+        self
+    }
+
+    /// Treats `c` as empty space, even if it would otherwise be a symbol.
+    pub fn with_empty(mut self, c: char) -> Self {
+        self.symbols.retain(|&s| s != c);
+        self.empty.push(c);
+        self
+    }
+
+    pub fn classify(&self, c: char) -> Cell {
+        if c.is_ascii_digit() {
+            Cell::Digit(c as u8 - b'0')
+        } else if self.symbols.contains(&c) {
+            Cell::Symbol(c)
+        } else if self.empty.contains(&c) || c.is_alphanumeric() || c == '_' {
+            Cell::Empty
+        } else {
+            Cell::Symbol(c)
+        }
+    }
+
+    /// Every number and symbol in `grid`, row by row, in a single pass over the cells.
+    /// Numbers never continue from one row onto the next.
+    pub fn tokenize(&self, grid: &Grid<char>) -> Result<Vec<Token>, ParseError> {
+        let mut tokens = Vec::new();
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut number: Option<(usize, Option<usize>)> = None;
+
+            for x in 0..=row.len() {
+                let cell = row.get(x).map_or(Cell::Empty, |&c| self.classify(c));
+
+                if let Cell::Digit(digit) = cell {
+                    let (_, value) = number.get_or_insert((x, Some(0)));
+                    *value = value
+                        .and_then(|v| v.checked_mul(10))
+                        .and_then(|v| v.checked_add(digit as usize));
+                    continue;
+                }
+
+                if let Some((start, value)) = number.take() {
+                    let value = value.ok_or_else(|| {
+                        ParseError::new(
+                            Day03::DAY,
+                            y + 1,
+                            start + 1,
+                            row[start..x].iter().collect::<String>(),
+                            "part number out of range",
+                        )
+                    })?;
+                    tokens.push(Token::Number {
+                        anchor: (start, y),
+                        width: x - start,
+                        value,
+                    });
+                }
+
+                if let Cell::Symbol(symbol) = cell {
+                    tokens.push(Token::Symbol {
+                        pos: (x, y),
+                        symbol,
+                    });
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let grid = Grid::parse(Day03::DAY, "467.*\n_a12#", Some).unwrap();
+        let tokens = Tokenizer::new().tokenize(&grid).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Number {
+                    anchor: (0, 0),
+                    width: 3,
+                    value: 467
+                },
+                Token::Symbol {
+                    pos: (4, 0),
+                    symbol: '*'
+                },
+                Token::Number {
+                    anchor: (2, 1),
+                    width: 2,
+                    value: 12
+                },
+                Token::Symbol {
+                    pos: (4, 1),
+                    symbol: '#'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symbol_classes() {
+        let tokenizer = Tokenizer::new().with_symbol('_').with_empty('#');
+        assert_eq!(tokenizer.classify('_'), Cell::Symbol('_'));
+        assert_eq!(tokenizer.classify('#'), Cell::Empty);
+        assert_eq!(tokenizer.classify('.'), Cell::Empty);
+        assert_eq!(tokenizer.classify('٣'), Cell::Empty);
+        assert_eq!(
+            Tokenizer::new().with_symbol('.').classify('.'),
+            Cell::Symbol('.')
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let grid = Grid::parse(Day03::DAY, ".#99999999999999999999999", Some).unwrap();
+        let err = Tokenizer::new().tokenize(&grid).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Option<Vec<usize>>>;
+    type Answer = usize;
+
+    /// Also rejects cards whose points or copies would overflow, so neither part can.
+    fn parse(input: &str) -> Result<Vec<Option<Vec<usize>>>, ParseError> {
+        let cards = parse_cards(input)?;
+
+        // Cards are parsed one per line, so a card's index is its line number.
+        let overflow = |index: usize, message| {
+            let line = input.lines().nth(index).unwrap_or_default();
+            ParseError::new(Day04::DAY, index + 1, 1, line, message)
+        };
+        points(&cards).map_err(|index| overflow(index, "card points out of range"))?;
+        copies(&cards).map_err(|index| overflow(index, "card copies out of range"))?;
+
+        Ok(cards)
+    }
+
+    fn part_01(cards: &Vec<Option<Vec<usize>>>) -> usize {
+        points(cards).expect("checked in parse")
+    }
+
+    fn part_02(cards: &Vec<Option<Vec<usize>>>) -> usize {
+        copies(cards).expect("checked in parse")
+    }
+}
+
+/// The total points of every card, or the index of the card at which they overflow.
+fn points(cards: &[Option<Vec<usize>>]) -> Result<usize, usize> {
+    cards
+        .iter()
+        .enumerate()
+        .try_fold(0_usize, |total, (index, card)| match card {
+            Some(matches) => 2_usize
+                .checked_pow(matches.len() as u32 - 1)
+                .and_then(|points| total.checked_add(points))
+                .ok_or(index),
+            None => Ok(total),
+        })
+}
+
+/// The total number of cards once every win has been copied, or the index of the card at
+/// which the count overflows.
+fn copies(cards: &[Option<Vec<usize>>]) -> Result<usize, usize> {
+    let mut winners = vec![1_usize; cards.len()];
+
+    for (index, winning) in cards.iter().enumerate() {
+        if let Some(winning) = winning {
+            for i in 0..winning.len() {
+                winners[index + i + 1] = winners[index + i + 1]
+                    .checked_add(winners[index])
+                    .ok_or(index)?;
+            }
+        }
+    }
+
+    winners
+        .iter()
+        .try_fold(0_usize, |total, &count| total.checked_add(count))
+        .ok_or(cards.len().saturating_sub(1))
+}
+
+fn parse_numbers(line: usize, source: &str, numbers: &str) -> Result<Vec<usize>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|n| {
+            n.parse::<usize>()
+                .map_err(|_| ParseError::at(Day04::DAY, line, source, n, "expected a number"))
+        })
+        .collect()
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Option<Vec<usize>>>, ParseError> {
+    let total = input.lines().count();
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, source)| {
+            let line = index + 1;
+            let (_, card) = source.split_once(':').ok_or_else(|| {
+                ParseError::at(Day04::DAY, line, source, source, "expected `Card <id>:`")
+            })?;
+            let (l, r) = card.split_once('|').ok_or_else(|| {
+                ParseError::at(
+                    Day04::DAY,
+                    line,
+                    source,
+                    card,
+                    "expected `|` between numbers",
+                )
+            })?;
+
+            let numbers = parse_numbers(line, source, l)?;
+            let have = parse_numbers(line, source, r)?;
+
+            let matches = numbers
+                .into_iter()
+                .filter(|n| have.contains(n))
+                .collect::<Vec<usize>>();
+
+            // Every match wins a copy of one of the following cards, so there must be enough.
+            if matches.len() > total - line {
+                return Err(ParseError::at(
+                    Day04::DAY,
+                    line,
+                    source,
+                    card.trim(),
+                    format!(
+                        "card wins {} copies but only {} cards follow it",
+                        matches.len(),
+                        total - line
+                    ),
+                ));
+            }
+
+            Ok(match matches.len() {
+                0 => None,
+                _ => Some(matches),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_01() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(Day04::part_01(&Day04::parse(input).unwrap()), 13);
+    }
+
+    #[test]
+    fn part_02() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(Day04::part_02(&Day04::parse(input).unwrap()), 30);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day04::parse("Card 1: 1 x | 1 2").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 11, "x"));
+
+        let input = "Card 1: 1 | 1 2
+Card 2: 1 2 | 1 2";
+        let err = Day04::parse(input).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 9));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let numbers = (1..=70)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut input = format!("Card 1: {} | {}", numbers, numbers);
+        for card in 2..=72 {
+            input.push_str(&format!("\nCard {}: 1 | 2", card));
+        }
+        let err = Day04::parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "card points out of range");
+
+        // Each card copying the next two grows the count like the Fibonacci numbers.
+        let input = (1..=100)
+            .map(|card| match card {
+                100 => format!("Card {}: 1 | 2", card),
+                99 => format!("Card {}: 1 | 1", card),
+                _ => format!("Card {}: 1 2 | 1 2", card),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let err = Day04::parse(&input).err().unwrap();
+        assert_eq!(err.message, "card copies out of range");
+    }
+}
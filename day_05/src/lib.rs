@@ -0,0 +1,415 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<MapRanges>,
+}
+
+struct MapRanges(Vec<MapRange>);
+
+struct MapRange {
+    source: usize,
+    destination: usize,
+    offset: usize,
+}
+
+fn remap(lo: usize, hi: usize, m: &MapRanges) -> Vec<(usize, usize)> {
+    let ans = m
+        .inner()
+        .iter()
+        .filter_map(
+            |MapRange {
+                 destination,
+                 source,
+                 offset,
+             }| {
+                let end = source + offset - 1;
+                let d = *destination as isize - *source as isize; // Shift amount
+
+                if !(end < lo || *source > hi) {
+                    Some((usize::max(*source, lo), usize::min(end, hi), d))
+                } else {
+                    None
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let mut result = ans
+        .iter()
+        .enumerate()
+        .fold(Vec::new(), |mut acc, (i, &(l, r, d))| {
+            acc.push((l.wrapping_add(d as usize), r.wrapping_add(d as usize)));
+
+            if i < ans.len() - 1 && ans[i + 1].0 > r + 1 {
+                acc.push((r + 1, ans[i + 1].0 - 1));
+            }
+
+            acc
+        });
+
+    if ans.is_empty() {
+        return vec![(lo, hi)];
+    }
+
+    if ans[0].0 > lo {
+        result.insert(0, (lo, ans[0].0 - 1));
+    }
+    if ans.last().unwrap().1 < hi {
+        result.push((ans.last().unwrap().1 + 1, hi));
+    }
+
+    result
+}
+
+impl MapRange {
+    pub fn new(source: usize, destination: usize, offset: usize) -> Self {
+        Self {
+            source,
+            destination,
+            offset,
+        }
+    }
+
+    pub fn map(&self, input: usize) -> Option<usize> {
+        if self.source <= input && input < self.source + self.offset {
+            Some(input - self.source + self.destination)
+        } else {
+            None
+        }
+    }
+}
+
+impl MapRanges {
+    fn map(&self, input: usize) -> usize {
+        self.0
+            .iter()
+            .find_map(|map| map.map(input))
+            .unwrap_or(input)
+    }
+
+    fn inner(&self) -> &Vec<MapRange> {
+        &self.0
+    }
+}
+
+fn parse_number(line: usize, source: &str, number: &str) -> Result<usize, ParseError> {
+    number
+        .parse()
+        .map_err(|_| ParseError::at(Day05::DAY, line, source, number, "expected a number"))
+}
+
+/// Seeds come in `<start> <length>` pairs for part two, so there must be an even number of
+/// them and no range may run past `usize::MAX`.
+fn parse_seeds(line: usize, source: &str) -> Result<Vec<usize>, ParseError> {
+    let numbers = source
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::at(Day05::DAY, line, source, source, "expected `seeds:`"))?
+        .split(' ')
+        .collect::<Vec<_>>();
+
+    if numbers.len() % 2 != 0 {
+        return Err(ParseError::at(
+            Day05::DAY,
+            line,
+            source,
+            numbers[numbers.len() - 1],
+            "expected seeds in `<start> <length>` pairs",
+        ));
+    }
+
+    let seeds = numbers
+        .iter()
+        .map(|s| parse_number(line, source, s))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (pair, seed) in numbers.chunks(2).zip(seeds.chunks(2)) {
+        if seed[0].checked_add(seed[1]).is_none() {
+            return Err(ParseError::at(
+                Day05::DAY,
+                line,
+                source,
+                pair[1],
+                "seed range out of range",
+            ));
+        }
+    }
+
+    Ok(seeds)
+}
+
+fn parse_range(line: usize, source: &str) -> Result<MapRange, ParseError> {
+    let mut parts = source.split(' ');
+    let mut next = || {
+        let part = parts.next().ok_or_else(|| {
+            ParseError::at(
+                Day05::DAY,
+                line,
+                source,
+                source,
+                "expected `<destination> <source> <length>`",
+            )
+        })?;
+        parse_number(line, source, part)
+    };
+    let destination = next()?;
+    let start = next()?;
+    let offset = next()?;
+
+    if let Some(extra) = parts.next() {
+        return Err(ParseError::at(
+            Day05::DAY,
+            line,
+            source,
+            extra,
+            "expected `<destination> <source> <length>`",
+        ));
+    }
+
+    if offset == 0 {
+        return Err(ParseError::at(
+            Day05::DAY,
+            line,
+            source,
+            source,
+            "range length must be positive",
+        ));
+    }
+
+    if start.checked_add(offset).is_none() || destination.checked_add(offset).is_none() {
+        return Err(ParseError::at(
+            Day05::DAY,
+            line,
+            source,
+            source,
+            "range extends past the largest number",
+        ));
+    }
+
+    Ok(MapRange::new(start, destination, offset))
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+
+    let seeds = match lines.next() {
+        Some((line, source)) => parse_seeds(line, source)?,
+        None => return Err(ParseError::new(Day05::DAY, 1, 1, "", "expected `seeds:`")),
+    };
+
+    let mut maps: Vec<MapRanges> = Vec::new();
+    for (line, source) in lines {
+        if source.is_empty() {
+            continue;
+        }
+
+        if source.ends_with("map:") {
+            maps.push(MapRanges(Vec::new()));
+            continue;
+        }
+
+        let range = parse_range(line, source)?;
+        match maps.last_mut() {
+            Some(MapRanges(ranges)) => ranges.push(range),
+            None => {
+                return Err(ParseError::at(
+                    Day05::DAY,
+                    line,
+                    source,
+                    source,
+                    "expected `<name> map:` header",
+                ))
+            }
+        }
+    }
+
+    for MapRanges(ranges) in maps.iter_mut() {
+        ranges.sort_by_key(|a| a.source);
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        parse(input)
+    }
+
+    fn part_01(Almanac { seeds, maps }: &Almanac) -> usize {
+        seeds
+            .iter()
+            .map(|seed| maps.iter().fold(*seed, |acc, map| map.map(acc)))
+            .min()
+            .unwrap()
+    }
+
+    fn part_02(Almanac { seeds, maps }: &Almanac) -> usize {
+        let range_seeds: Vec<(usize, usize)> =
+            seeds.chunks(2).map(|c| (c[0], c[0] + c[1])).collect();
+
+        range_seeds.iter().fold(usize::MAX, |acc, &(start, r)| {
+            let cur_intervals = maps.iter().fold(vec![(start, r)], |cur_intervals, map| {
+                cur_intervals
+                    .iter()
+                    .flat_map(|&(lo, hi)| remap(lo, hi, map))
+                    .collect()
+            });
+
+            cur_intervals
+                .iter()
+                .fold(acc, |min_ans, &(lo, _)| usize::min(min_ans, lo))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_source() {
+        let map = MapRange {
+            destination: 50,
+            source: 98,
+            offset: 2,
+        };
+
+        assert_eq!(map.map(98), Some(50));
+        assert_eq!(map.map(99), Some(51));
+        assert_eq!(map.map(100), None);
+    }
+
+    #[test]
+    fn test_map_destination() {
+        let maps = MapRanges(vec![
+            MapRange {
+                destination: 50,
+                source: 98,
+                offset: 2,
+            },
+            MapRange {
+                destination: 52,
+                source: 50,
+                offset: 48,
+            },
+        ]);
+
+        assert_eq!(maps.map(0), 0);
+        assert_eq!(maps.map(1), 1);
+        assert_eq!(maps.map(48), 48);
+        assert_eq!(maps.map(49), 49);
+        assert_eq!(maps.map(50), 52);
+        assert_eq!(maps.map(51), 53);
+        assert_eq!(maps.map(96), 98);
+        assert_eq!(maps.map(97), 99);
+        assert_eq!(maps.map(98), 50);
+        assert_eq!(maps.map(99), 51);
+    }
+
+    #[test]
+    fn test_part_01() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        assert_eq!(Day05::part_01(&Day05::parse(input).unwrap()), 35);
+    }
+
+    #[test]
+    fn test_part_02() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        assert_eq!(Day05::part_02(&Day05::parse(input).unwrap()), 46);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day05::parse("seeds: 79 14 55").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 14, "55"));
+
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+18446744073709551615 0 2";
+        let err = Day05::parse(input).err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2 7";
+        let err = Day05::parse(input).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 9, "7"));
+    }
+}
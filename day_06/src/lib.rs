@@ -0,0 +1,168 @@
+use aoc_core::{ParseError, Solution};
+use regex::Regex;
+
+pub struct Races {
+    races: Vec<(usize, usize)>,
+    race: (usize, usize),
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Races;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        let re = Regex::new(r"\d+").unwrap();
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line));
+
+        // A missing line is reported just past the last line read.
+        let mut next_line = 1;
+        let mut parse_line = |label: &str| -> Result<(Vec<usize>, usize), ParseError> {
+            let (line, source) = lines.next().ok_or_else(|| {
+                ParseError::new(
+                    Day06::DAY,
+                    next_line,
+                    1,
+                    "",
+                    format!("expected `{}` line", label),
+                )
+            })?;
+            next_line = line + 1;
+            let numbers = source.strip_prefix(label).ok_or_else(|| {
+                ParseError::at(
+                    Day06::DAY,
+                    line,
+                    source,
+                    source,
+                    format!("expected `{}`", label),
+                )
+            })?;
+
+            let tokens = re
+                .find_iter(numbers)
+                .map(|x| x.as_str())
+                .collect::<Vec<_>>();
+            if tokens.is_empty() {
+                return Err(ParseError::at(
+                    Day06::DAY,
+                    line,
+                    source,
+                    source,
+                    "expected at least one number",
+                ));
+            }
+            let values = tokens
+                .iter()
+                .map(|x| {
+                    x.parse::<usize>().map_err(|_| {
+                        ParseError::at(Day06::DAY, line, source, x, "number out of range")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let joined = tokens.concat().parse::<usize>().map_err(|_| {
+                ParseError::at(
+                    Day06::DAY,
+                    line,
+                    source,
+                    numbers.trim(),
+                    "joined number out of range",
+                )
+            })?;
+
+            Ok((values, joined))
+        };
+
+        let (times, time) = parse_line("Time:")?;
+        let (records, record) = parse_line("Distance:")?;
+
+        if times.len() != records.len() {
+            return Err(ParseError::new(
+                Day06::DAY,
+                2,
+                1,
+                input.lines().nth(1).unwrap_or_default(),
+                format!(
+                    "expected {} distances, found {}",
+                    times.len(),
+                    records.len()
+                ),
+            ));
+        }
+
+        Ok(Races {
+            races: times.into_iter().zip(records).collect(),
+            race: (time, record),
+        })
+    }
+
+    fn part_01(Races { races, .. }: &Races) -> usize {
+        races
+            .iter()
+            .map(|(race_length, race_record)| {
+                let breakers: Vec<usize> = (0..*race_length)
+                    .filter_map(|input_time| {
+                        let d = input_time * (race_length - input_time);
+
+                        match d > *race_record {
+                            true => Some(1),
+                            false => None,
+                        }
+                    })
+                    .collect();
+
+                <usize as TryInto<usize>>::try_into(breakers.len()).unwrap()
+            })
+            .product()
+    }
+
+    fn part_02(&Races { race, .. }: &Races) -> usize {
+        (0..race.0)
+            .filter_map(|input_time| {
+                let d = input_time * (race.0 - input_time);
+
+                match d > race.1 {
+                    true => Some(1),
+                    false => None,
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_part_01() {
+        assert_eq!(Day06::part_01(&Day06::parse(INPUT).unwrap()), 288);
+    }
+
+    #[test]
+    fn test_part_02() {
+        assert_eq!(Day06::part_02(&Day06::parse(INPUT).unwrap()), 71503);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day06::parse("Time: 7\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected `Distance:` line");
+
+        let err = Day06::parse("Time: 7\nDist: 9").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "Dist: 9"));
+
+        let err = Day06::parse("Time:\nDistance: 9").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "expected at least one number");
+    }
+}